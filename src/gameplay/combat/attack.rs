@@ -0,0 +1,120 @@
+// src/gameplay/combat/attack.rs
+//! ① 기본 피해 → 치명타 → 쉴드 흡수 → 체력 감소
+//! ECS와 무관한 순수 함수(스냅샷 입력 → 결과 반환). 미리보기/AI/테스트가 그대로 공유.
+use crate::gameplay::units::components::{Attack, Stats};
+
+/// 한 번의 피해 적용 내역
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DamageBreakdown {
+    /// 치명타 적용 전 기본 피해
+    pub base: i32,
+    /// 치명타 여부
+    pub crit: bool,
+    /// 치명타 적용 후 총 피해(쉴드 흡수 전)
+    pub total: i32,
+    /// 쉴드가 흡수한 양
+    pub absorbed: i32,
+    /// 체력에 들어간 양
+    pub hp_damage: i32,
+    /// 적용 전/후 스탯
+    pub before: Stats,
+    pub after: Stats,
+    /// 이번 피해로 사망했는지(이미 죽어 있었으면 false)
+    pub killed: bool,
+}
+
+/// 기본 피해 + 치명타 판정
+/// - `crit_roll`은 [0, 1) 난수. `crit_roll < crit_chance`면 치명타
+/// - 치명타 피해 = round(damage * crit_mult), 음수 피해는 0
+#[inline]
+pub fn roll_damage(attack: &Attack, crit_roll: f32) -> (i32, bool) {
+    let base = attack.damage.max(0);
+    let crit = crit_roll < attack.crit_chance;
+    let total = if crit { (base as f32 * attack.crit_mult).round() as i32 } else { base };
+    (total.max(0), crit)
+}
+
+/// 확정된 피해량을 쉴드 → 체력 순으로 적용 (치명타 없음: 폭발/충돌 등 부가 피해에도 사용)
+pub fn apply_damage(target: Stats, base: i32, total: i32, crit: bool) -> DamageBreakdown {
+    let total = total.max(0);
+    let mut after = target;
+    let absorbed = total.min(after.shield.max(0));
+    after.shield -= absorbed;
+    let hp_damage = total - absorbed;
+    after.hp -= hp_damage;
+    after.clamp();
+    DamageBreakdown {
+        base,
+        crit,
+        total,
+        absorbed,
+        hp_damage,
+        before: target,
+        after,
+        killed: !target.is_dead() && after.is_dead(),
+    }
+}
+
+/// 공격 1히트 해석: roll_damage → apply_damage
+#[inline]
+pub fn resolve_hit(attack: &Attack, target: Stats, crit_roll: f32) -> DamageBreakdown {
+    let (total, crit) = roll_damage(attack, crit_roll);
+    apply_damage(target, attack.damage.max(0), total, crit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::units::components::AimDirs;
+
+    fn attack(damage: i32, crit_chance: f32, crit_mult: f32) -> Attack {
+        Attack { damage, range: 3, crit_chance, crit_mult, dirs: AimDirs::Four, ap_cost: 1 }
+    }
+
+    #[test]
+    fn crit_only_below_chance() {
+        let a = attack(4, 0.25, 1.5);
+        assert_eq!(roll_damage(&a, 0.24), (6, true));
+        // 경계값: roll == crit_chance면 치명타 아님
+        assert_eq!(roll_damage(&a, 0.25), (4, false));
+        assert_eq!(roll_damage(&attack(4, 0.0, 2.0), 0.0), (4, false));
+        assert_eq!(roll_damage(&attack(4, 1.0, 2.0), 0.999), (8, true));
+    }
+
+    #[test]
+    fn crit_rounds_and_clamps_negative() {
+        assert_eq!(roll_damage(&attack(3, 1.0, 1.5), 0.0), (5, true)); // 4.5 → 5
+        assert_eq!(roll_damage(&attack(-2, 1.0, 1.5), 0.0), (0, true));
+    }
+
+    #[test]
+    fn shield_absorbs_part() {
+        let b = apply_damage(Stats::new(10, 2), 5, 5, false);
+        assert_eq!((b.absorbed, b.hp_damage), (2, 3));
+        assert_eq!((b.after.shield, b.after.hp), (0, 7));
+        assert!(!b.killed);
+    }
+
+    #[test]
+    fn shield_absorbs_all() {
+        let b = apply_damage(Stats::new(10, 4), 3, 3, false);
+        assert_eq!((b.absorbed, b.hp_damage), (3, 0));
+        assert_eq!((b.after.shield, b.after.hp), (1, 10));
+        assert!(!b.killed);
+    }
+
+    #[test]
+    fn killed_only_on_transition() {
+        let b = resolve_hit(&attack(5, 0.0, 1.5), Stats::new(4, 0), 0.5);
+        assert!(b.killed);
+        assert_eq!(b.after.hp, 0);
+
+        // 이미 죽은 대상은 다시 killed가 아님
+        let b = resolve_hit(&attack(5, 0.0, 1.5), b.after, 0.5);
+        assert!(!b.killed);
+
+        // 정확히 0이 되는 피해도 사망
+        let b = resolve_hit(&attack(4, 0.0, 1.5), Stats::new(4, 0), 0.5);
+        assert!(b.killed);
+    }
+}
@@ -0,0 +1,106 @@
+// src/gameplay/combat/context.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::api::TilesApi;
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::tiles::events::GridCommand;
+use crate::gameplay::units::components::{Stats, StatusEffects, TeamId, Unit};
+
+use super::{
+    firing::Projectile,
+    pipeline::{AttackTrace, Shot, resolve_attack},
+    resources::{CombatConfig, CombatRng},
+    snapshot::{CombatWorld, UnitSnap},
+    synergy::SynergyMatrix,
+};
+
+/// 스냅샷에 담는 유닛 컴포넌트
+type SnapSource = (
+    Entity,
+    &'static GridPos,
+    &'static TeamId,
+    &'static mut Stats,
+    Option<&'static mut StatusEffects>,
+);
+
+/// 공격 해석 시스템 공용 파라미터: 맵/점유 + 유닛 스탯/상태이상 + 난수/규칙
+#[derive(SystemParam)]
+pub struct CombatCtx<'w, 's> {
+    pub tiles: TilesApi<'w>,
+    q_units: Query<'w, 's, SnapSource, With<Unit>>,
+    rng: ResMut<'w, CombatRng>,
+    config: Res<'w, CombatConfig>,
+    synergy: Res<'w, SynergyMatrix>,
+}
+
+impl<'w, 's> CombatCtx<'w, 's> {
+    /// 현재 ECS 상태로 스냅샷을 만들어 공격 1회 해석 → 맞은 유닛 Stats, 바뀐 StatusEffects 반영
+    /// - 위치가 바뀐 생존 유닛은 GridBatch 하나로 이동(다음 PreUpdate 적용)
+    /// - 맵/인덱스가 아직 없으면 None
+    pub fn resolve(&mut self, shot: &Shot, projectiles: Vec<Projectile>) -> Option<AttackTrace> {
+        let (Some(map), Some(index)) = (self.tiles.map.as_deref(), self.tiles.index.as_deref()) else {
+            return None;
+        };
+
+        let mut world = CombatWorld::new(map, index);
+        for (entity, pos, team, stats, statuses) in &self.q_units {
+            let statuses = statuses.copied().unwrap_or_default();
+            world.insert(entity, UnitSnap { pos: *pos, team: *team, stats: *stats, statuses });
+        }
+        let rng = &mut self.rng;
+        let trace = resolve_attack(&mut world, shot, projectiles, &self.config, &self.synergy, &mut || rng.roll());
+
+        for hit in &trace.hits {
+            if let (Ok((_, _, _, mut stats, _)), Some(unit)) = (self.q_units.get_mut(hit.target), world.unit(hit.target)) {
+                *stats = unit.stats;
+            }
+        }
+        // 상태이상은 맞지 않은 유닛도 바뀔 수 있음(시너지 확산)
+        for (entity, _, _, _, statuses) in &mut self.q_units {
+            if let (Some(mut statuses), Some(unit)) = (statuses, world.unit(entity)) {
+                statuses.set_if_neq(unit.statuses);
+            }
+        }
+
+        // 죽은 유닛은 원래 칸에서 사망 처리(lifecycle의 GridRemove)에 맡김
+        let mut moved: Vec<Entity> = trace.displacements.iter().map(|d| d.target).collect();
+        moved.sort();
+        moved.dedup();
+        let moves: Vec<_> = moved
+            .into_iter()
+            .filter_map(|e| {
+                let (_, from, ..) = self.q_units.get(e).ok()?;
+                let unit = world.unit(e).filter(|u| !u.stats.is_dead() && u.pos != *from)?;
+                Some((e, *from, unit.pos))
+            })
+            .collect();
+        if !moves.is_empty() {
+            self.tiles.batch(ordered_moves(moves));
+        }
+        Some(trace)
+    }
+}
+
+/// 순 이동 목록 → 순서대로 적용해도 칸이 겹치지 않는 GridCommand 순서
+/// - 목적지가 아직 안 떠난 다른 이동의 출발 칸이면 그 이동을 먼저
+/// - 순환(서로 자리 바꿈 등)이 남으면 남은 것 전부 Remove 후 Place
+fn ordered_moves(mut pending: Vec<(Entity, GridPos, GridPos)>) -> Vec<GridCommand> {
+    let mut ops = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready = pending
+            .iter()
+            .position(|&(_, _, to)| !pending.iter().any(|&(_, from, _)| from == to));
+        match ready {
+            Some(i) => {
+                let (e, from, to) = pending.remove(i);
+                ops.push(GridCommand::r#move(e, from, to));
+            }
+            None => {
+                ops.extend(pending.iter().map(|&(e, from, _)| GridCommand::remove(e, from)));
+                ops.extend(pending.drain(..).map(|(e, _, to)| GridCommand::place(e, to)));
+            }
+        }
+    }
+    ops
+}
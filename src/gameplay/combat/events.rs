@@ -0,0 +1,59 @@
+// src/gameplay/combat/events.rs
+use bevy::prelude::*;
+use super::attack::DamageBreakdown;
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::units::components::{Element, StatusEffects};
+
+use super::pipeline::AttackTrace;
+
+/// 피해 적용 완료(Stats 반영 후)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DamageApplied {
+    /// 가해자 (환경 피해 등은 None)
+    pub source: Option<Entity>,
+    pub target: Entity,
+    pub breakdown: DamageBreakdown,
+}
+
+/// 공격 1회 해석 완료 — 모든 히트를 적용 순서대로 담은 trace (로그/미리보기/VFX용)
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct AttackApplied {
+    pub trace: AttackTrace,
+}
+
+/// 넉백 적용 완료 — 뷰가 경로를 따라 애니메이션
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct KnockbackApplied {
+    pub attacker: Entity,
+    pub target: Entity,
+    pub from: GridPos,
+    /// 지나간 칸(시작 칸 제외, 마지막 = 도착 칸). 바로 막혔으면 빈 Vec
+    pub path: Vec<GridPos>,
+    /// 충돌한 유닛(양쪽 모두 충돌 피해)
+    pub collided: Option<Entity>,
+}
+
+/// 속성 부여 완료 — statuses = 부여 후 상태(HUD 아이콘 갱신용)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementApplied {
+    pub source: Entity,
+    pub target: Entity,
+    pub element: Element,
+    pub statuses: StatusEffects,
+}
+
+/// 시너지 반응 발생 — HUD/VFX 구독용
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct SynergyTriggered {
+    pub attacker: Entity,
+    pub target: Entity,
+    pub at: GridPos,
+    /// 대상에 걸려 있던 속성
+    pub existing: Element,
+    /// 새로 착탄한 속성
+    pub incoming: Element,
+    /// 반응 이름(반응표의 name)
+    pub name: String,
+    /// 확산으로 속성이 번진 유닛
+    pub spread: Vec<Entity>,
+}
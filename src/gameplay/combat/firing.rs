@@ -0,0 +1,148 @@
+// src/gameplay/combat/firing.rs
+//! 발사 단계: FiringMods(연사/멀티샷/부채꼴) → 투사체 목록
+//! 생성된 투사체 하나하나가 pipeline의 명중 → OnHit → 전파를 따로 거친다.
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::tiles::resources::BaseTileMap;
+use crate::gameplay::units::components::{AimDirs, FiringMods};
+
+use super::propagation::rotate45;
+
+/// 투사체 1발
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Projectile {
+    /// 연사 회차(0 = 첫 일제 사격)
+    pub volley: u8,
+    /// 부채꼴 조준선 번호(0 = 원래 조준선)
+    pub ray: u8,
+    /// 같은 조준선 위 멀티샷 번호
+    pub lane: u8,
+    /// 진행 방향
+    pub dir: (i32, i32),
+    /// 착탄 칸
+    /// - 생성 시: 원래 조준선은 조준 타일, 부채꼴은 같은 거리(맵 끝에서 잘림)
+    /// - 해석 후: 사선 판정으로 실제 멈춘 칸(직사는 첫 벽/유닛)
+    pub at: GridPos,
+}
+
+/// 부채꼴 조준선 방향 (결정적)
+/// - 원래 조준선 → 좌 → 우 → 더 넓게 … 순서, 왼쪽(반시계) 먼저
+/// - 각도 간격: AimDirs::Four면 90°(직선만), Eight면 45°
+/// - 겹치지 않는 방향 수(4 / 8)까지만
+pub fn fan_dirs(aim: (i32, i32), rays: u8, dirs: AimDirs) -> Vec<(i32, i32)> {
+    const ORDER: [i32; 8] = [0, 1, -1, 2, -2, 3, -3, 4];
+    let (step, max) = match dirs {
+        AimDirs::Four => (2, 4),
+        AimDirs::Eight => (1, 8),
+    };
+    let n = (rays.max(1) as usize).min(max);
+    ORDER[..n].iter().map(|&k| rotate45(aim, k * step)).collect()
+}
+
+/// from에서 dir로 distance칸 — 맵 밖으로 나가면 마지막 맵 안 칸 (한 칸도 못 가면 None)
+fn landing(map: &BaseTileMap, from: GridPos, dir: (i32, i32), distance: u32) -> Option<GridPos> {
+    let mut last = None;
+    for i in 1..=distance as i32 {
+        let x = from.x as i32 + dir.0 * i;
+        let y = from.y as i32 + dir.1 * i;
+        if x < 0 || y < 0 { break; }
+        let p = GridPos::new(x as u32, y as u32);
+        if !map.in_bounds_pos(p) { break; }
+        last = Some(p);
+    }
+    last
+}
+
+/// 공격 1회의 투사체 목록 (적용 순서 그대로)
+/// - burst: 일제 사격 전체를 1 + burst회 반복
+/// - fan_rays: 일제 사격마다 부채꼴 조준선(fan_dirs)
+/// - multishot_rays: 조준선마다 같은 선 위로 나란히 여러 발
+/// - 순서: 연사 회차 → 조준선 → 멀티샷
+pub fn generate_projectiles(
+    map: &BaseTileMap,
+    from: GridPos,
+    at: GridPos,
+    aim: (i32, i32),
+    distance: u32,
+    mods: &FiringMods,
+    dirs: AimDirs,
+) -> Vec<Projectile> {
+    let lanes = mods.multishot_rays.max(1);
+    let rays: Vec<(u8, (i32, i32), GridPos)> = fan_dirs(aim, mods.fan_rays, dirs)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, dir)| {
+            let land = if i == 0 { Some(at) } else { landing(map, from, dir, distance) };
+            land.map(|p| (i as u8, dir, p))
+        })
+        .collect();
+
+    let mut out = Vec::with_capacity(mods.total_shots_per_action() as usize);
+    for volley in 0..=mods.burst {
+        for &(ray, dir, at) in &rays {
+            for lane in 0..lanes {
+                out.push(Projectile { volley, ray, lane, dir, at });
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::tiles::components::TerrainKind;
+
+    fn mods(burst: u8, multishot_rays: u8, fan_rays: u8) -> FiringMods {
+        FiringMods { burst, multishot_rays, fan_rays, lobbed: false }
+    }
+
+    fn open_map() -> BaseTileMap {
+        BaseTileMap::filled(10, 10, TerrainKind::Ground)
+    }
+
+    #[test]
+    fn projectile_count_matches_total_shots_per_action() {
+        let map = open_map();
+        let (from, at) = (GridPos::new(5, 5), GridPos::new(7, 5));
+        for (burst, multi, fan) in [(0, 0, 0), (2, 0, 0), (0, 3, 0), (0, 0, 3), (1, 2, 3), (2, 3, 5)] {
+            let m = mods(burst, multi, fan);
+            let shots = generate_projectiles(&map, from, at, (1, 0), 2, &m, AimDirs::Eight);
+            assert_eq!(shots.len() as u32, m.total_shots_per_action(), "{m:?}");
+        }
+    }
+
+    #[test]
+    fn projectiles_ordered_volley_then_ray_then_lane() {
+        let map = open_map();
+        let (from, at) = (GridPos::new(5, 5), GridPos::new(7, 5));
+        let shots = generate_projectiles(&map, from, at, (1, 0), 2, &mods(1, 2, 2), AimDirs::Eight);
+        let order: Vec<(u8, u8, u8)> = shots.iter().map(|p| (p.volley, p.ray, p.lane)).collect();
+        assert_eq!(
+            order,
+            vec![(0, 0, 0), (0, 0, 1), (0, 1, 0), (0, 1, 1), (1, 0, 0), (1, 0, 1), (1, 1, 0), (1, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn fan_offsets_are_90_degrees_for_four_and_45_for_eight() {
+        assert_eq!(fan_dirs((1, 0), 3, AimDirs::Four), vec![(1, 0), (0, 1), (0, -1)]);
+        assert_eq!(fan_dirs((1, 0), 3, AimDirs::Eight), vec![(1, 0), (1, 1), (1, -1)]);
+        // 겹치지 않는 방향 수까지만
+        assert_eq!(fan_dirs((1, 0), 6, AimDirs::Four), vec![(1, 0), (0, 1), (0, -1), (-1, 0)]);
+        assert_eq!(fan_dirs((1, 0), 12, AimDirs::Eight).len(), 8);
+        // 0 = 원래 조준선만
+        assert_eq!(fan_dirs((0, 1), 0, AimDirs::Eight), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn fan_rays_with_nowhere_to_land_are_dropped_and_others_clipped_at_edge() {
+        let map = open_map();
+        // 왼쪽 끝 (0,8)에서 오른쪽 조준: 뒤쪽(-1,0) 조준선은 한 칸도 못 가서 빠짐, 아래쪽은 맵 끝(0,9)에서 잘림
+        let (from, at) = (GridPos::new(0, 8), GridPos::new(3, 8));
+        let shots = generate_projectiles(&map, from, at, (1, 0), 3, &mods(0, 0, 4), AimDirs::Four);
+        let dirs: Vec<(i32, i32)> = shots.iter().map(|p| p.dir).collect();
+        let lands: Vec<GridPos> = shots.iter().map(|p| p.at).collect();
+        assert_eq!(dirs, vec![(1, 0), (0, 1), (0, -1)]);
+        assert_eq!(lands, vec![GridPos::new(3, 8), GridPos::new(0, 9), GridPos::new(0, 5)]);
+    }
+}
@@ -0,0 +1,30 @@
+// src/gameplay/combat/mod.rs
+pub mod attack;
+pub mod snapshot;
+pub mod propagation;
+pub mod onhit;
+pub mod firing;
+pub mod targeting;
+pub mod statuses;
+pub mod synergy;
+pub mod pipeline;
+pub mod context;
+pub mod events;
+pub mod resources;
+pub mod rules;
+pub mod systems;
+pub mod plugin;
+
+pub use plugin::{CombatPlugin, CombatSet};
+pub use attack::{DamageBreakdown, resolve_hit, roll_damage, apply_damage};
+pub use events::{AttackApplied, DamageApplied, ElementApplied, KnockbackApplied, SynergyTriggered};
+pub use pipeline::{
+    AttackTrace, Displacement, DisplacementKind, HitCause, HitRecord, Shot, SynergyRecord, resolve_attack,
+};
+pub use firing::{Projectile, fan_dirs, generate_projectiles};
+pub use targeting::{LineOfFire, line_of_fire};
+pub use statuses::{StatusConfig, apply_element, tick_damage};
+pub use synergy::{Synergy, SynergyEffect, SynergyMatrix};
+pub use snapshot::{CombatWorld, UnitSnap};
+pub use resources::{CombatConfig, CombatRng, FriendlyFire};
+pub use context::CombatCtx;
@@ -0,0 +1,231 @@
+// src/gameplay/combat/onhit.rs
+//! ③ OnHit: 명중 지점 주변에 추가 효과(폭발/넉백/중력 등) — 대상/경로 계산만, 피해·이동 적용은 pipeline
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::units::components::TeamId;
+
+use super::{resources::FriendlyFire, snapshot::CombatWorld};
+
+/// 폭발 피해 대상: center 기준 체비셰프 거리 radius 이내(중심 칸 포함)의 살아 있는 유닛
+/// - 아군 포함 여부는 FriendlyFire 정책
+/// - 순서: y → x (적용 순서 고정)
+pub fn explosion_victims(
+    world: &CombatWorld,
+    center: GridPos,
+    radius: u8,
+    attacker: Entity,
+    team: TeamId,
+    ff: FriendlyFire,
+) -> Vec<(Entity, GridPos)> {
+    let mut out: Vec<(Entity, GridPos)> = world
+        .alive_units()
+        .filter(|(_, u)| center.chebyshev(u.pos) <= radius as u32)
+        .filter(|&(e, u)| ff.affects(e == attacker, u.team == team))
+        .map(|(e, u)| (e, u.pos))
+        .collect();
+    out.sort_by_key(|&(_, p)| (p.y, p.x));
+    out
+}
+
+/// 넉백 경로 계산 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushPath {
+    /// 지나간 칸(시작 칸 제외, 마지막 = 도착 칸). 못 움직였으면 빈 Vec
+    pub path: Vec<GridPos>,
+    /// 다른 유닛에 막혀 멈췄으면 그 유닛
+    pub collided: Option<Entity>,
+}
+
+/// from에서 dir로 최대 distance칸 밀기
+/// - 벽/물/맵 끝 직전에서 정지 (충돌 아님)
+/// - 다른 유닛(이번 해석에서 죽었어도 칸을 차지하면 포함) 직전에서 정지 → collided
+pub fn push_path(world: &CombatWorld, from: GridPos, dir: (i32, i32), distance: u8) -> PushPath {
+    let mut out = PushPath { path: Vec::with_capacity(distance as usize), collided: None };
+    let mut p = from;
+    for _ in 0..distance {
+        let Some(next) = world.step(p, dir).filter(|&q| !world.blocks_push(q)) else { break; };
+        if let Some(other) = world.occupant(next) {
+            out.collided = Some(other);
+            break;
+        }
+        out.path.push(next);
+        p = next;
+    }
+    out
+}
+
+/// a → b 방향의 단위 벡터(8방향 근사)
+#[inline]
+pub fn away_from(a: GridPos, b: GridPos) -> (i32, i32) {
+    ((b.x as i32 - a.x as i32).signum(), (b.y as i32 - a.y as i32).signum())
+}
+
+/// 중력 대상: center 기준 체비셰프 거리 range 이내의 살아 있는 유닛(공격자/중심 칸 제외)
+/// - 순서: 가까운 순(체비셰프 → 맨해튼 → y → x) — 먼저 당겨진 유닛이 칸을 선점
+pub fn pull_candidates(
+    world: &CombatWorld,
+    center: GridPos,
+    range: u8,
+    attacker: Entity,
+) -> Vec<(Entity, GridPos)> {
+    let mut out: Vec<(Entity, GridPos)> = world
+        .alive_units()
+        .filter(|&(e, u)| e != attacker && u.pos != center)
+        .filter(|(_, u)| center.chebyshev(u.pos) <= range as u32)
+        .map(|(e, u)| (e, u.pos))
+        .collect();
+    out.sort_by_key(|&(_, p)| (center.chebyshev(p), center.manhattan(p), p.y, p.x));
+    out
+}
+
+/// from에서 center 쪽으로 최대 strength칸 당기기
+/// - 매 칸 center 방향(8방향 근사)을 다시 계산
+/// - 벽/물/맵 끝/다른 유닛 직전에서 정지 (충돌 피해 없음, 막은 유닛은 collided로 기록)
+pub fn pull_path(world: &CombatWorld, from: GridPos, center: GridPos, strength: u8) -> PushPath {
+    let mut out = PushPath { path: Vec::with_capacity(strength as usize), collided: None };
+    let mut p = from;
+    for _ in 0..strength {
+        let dir = away_from(p, center);
+        if dir == (0, 0) { break; }
+        let Some(next) = world.step(p, dir).filter(|&q| !world.blocks_push(q)) else { break; };
+        if let Some(other) = world.occupant(next) {
+            out.collided = Some(other);
+            break;
+        }
+        out.path.push(next);
+        p = next;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::combat::snapshot::UnitSnap;
+    use crate::gameplay::tiles::components::TerrainKind;
+    use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+    use crate::gameplay::units::components::Stats;
+
+    /// (id, x, y) 유닛을 10x10 평지에 배치
+    fn grid(units: &[(u32, u32, u32)]) -> (BaseTileMap, GridIndex) {
+        let map = BaseTileMap::filled(10, 10, TerrainKind::Ground);
+        let mut index = GridIndex::new(10, 10);
+        for &(id, x, y) in units {
+            index.place(x, y, Entity::from_raw(id)).unwrap();
+        }
+        (map, index)
+    }
+
+    fn world<'a>(map: &'a BaseTileMap, index: &'a GridIndex, units: &[(u32, u32, u32)]) -> CombatWorld<'a> {
+        let mut w = CombatWorld::new(map, index);
+        for &(id, x, y) in units {
+            let snap = UnitSnap {
+                pos: GridPos::new(x, y),
+                team: TeamId(0),
+                stats: Stats::new(10, 0),
+                statuses: Default::default(),
+            };
+            w.insert(Entity::from_raw(id), snap);
+        }
+        w
+    }
+
+    fn cells(path: &[GridPos]) -> Vec<(u32, u32)> {
+        path.iter().map(|p| (p.x, p.y)).collect()
+    }
+
+    #[test]
+    fn push_travels_full_distance_on_open_ground() {
+        let (map, index) = grid(&[(1, 3, 5)]);
+        let w = world(&map, &index, &[(1, 3, 5)]);
+        let push = push_path(&w, GridPos::new(3, 5), (1, 0), 3);
+        assert_eq!(cells(&push.path), vec![(4, 5), (5, 5), (6, 5)]);
+        assert_eq!(push.collided, None);
+    }
+
+    #[test]
+    fn push_stops_before_wall_water_and_edge_without_collision() {
+        let (mut map, index) = grid(&[]);
+        let i = map.idx(5, 5);
+        map.terrain[i] = TerrainKind::Wall;
+        let i = map.idx(2, 7);
+        map.terrain[i] = TerrainKind::Water;
+        let w = world(&map, &index, &[]);
+
+        let wall = push_path(&w, GridPos::new(3, 5), (1, 0), 3);
+        assert_eq!(cells(&wall.path), vec![(4, 5)]);
+        assert_eq!(wall.collided, None);
+
+        let water = push_path(&w, GridPos::new(2, 5), (0, 1), 3);
+        assert_eq!(cells(&water.path), vec![(2, 6)]);
+
+        let edge = push_path(&w, GridPos::new(1, 1), (-1, -1), 3);
+        assert_eq!(cells(&edge.path), vec![(0, 0)]);
+        assert_eq!(edge.collided, None);
+    }
+
+    #[test]
+    fn push_into_unit_stops_and_reports_collision() {
+        let units = [(1, 3, 5), (2, 6, 5)];
+        let (map, index) = grid(&units);
+        let w = world(&map, &index, &units);
+        let push = push_path(&w, GridPos::new(3, 5), (1, 0), 4);
+        assert_eq!(cells(&push.path), vec![(4, 5), (5, 5)]);
+        assert_eq!(push.collided, Some(Entity::from_raw(2)));
+
+        // 바로 옆에 유닛 → 움직이지 못하고 충돌만
+        let push = push_path(&w, GridPos::new(5, 5), (1, 0), 2);
+        assert!(push.path.is_empty());
+        assert_eq!(push.collided, Some(Entity::from_raw(2)));
+    }
+
+    #[test]
+    fn away_from_is_unit_direction_from_attacker() {
+        assert_eq!(away_from(GridPos::new(2, 2), GridPos::new(5, 2)), (1, 0));
+        assert_eq!(away_from(GridPos::new(5, 5), GridPos::new(2, 1)), (-1, -1));
+        assert_eq!(away_from(GridPos::new(3, 3), GridPos::new(3, 3)), (0, 0));
+    }
+
+    #[test]
+    fn pull_candidates_skip_attacker_and_center_closest_first() {
+        // 중심 (5,5): 1 공격자, 2 중심 칸, 3 대각 1칸, 4 직선 1칸, 5 직선 2칸, 6 범위 밖
+        let units = [(1, 5, 3), (2, 5, 5), (3, 6, 6), (4, 4, 5), (5, 7, 5), (6, 9, 5)];
+        let (map, index) = grid(&units);
+        let w = world(&map, &index, &units);
+        let got: Vec<u32> = pull_candidates(&w, GridPos::new(5, 5), 2, Entity::from_raw(1))
+            .iter()
+            .map(|(e, _)| e.index())
+            .collect();
+        assert_eq!(got, vec![4, 3, 5]);
+    }
+
+    #[test]
+    fn pull_steers_toward_center_each_step() {
+        let (map, index) = grid(&[]);
+        let w = world(&map, &index, &[]);
+        // (1,3) → (5,5): 대각 두 번으로 y를 맞춘 뒤 직선
+        let pull = pull_path(&w, GridPos::new(1, 3), GridPos::new(5, 5), 3);
+        assert_eq!(cells(&pull.path), vec![(2, 4), (3, 5), (4, 5)]);
+        assert_eq!(pull.collided, None);
+
+        // 중심에 닿기 전(바로 옆)에서 멈춤 — 중심 칸의 유닛과는 충돌로 기록
+        let units = [(2, 5, 5)];
+        let (map, index) = grid(&units);
+        let w = world(&map, &index, &units);
+        let pull = pull_path(&w, GridPos::new(2, 5), GridPos::new(5, 5), 5);
+        assert_eq!(cells(&pull.path), vec![(3, 5), (4, 5)]);
+        assert_eq!(pull.collided, Some(Entity::from_raw(2)));
+    }
+
+    #[test]
+    fn pull_is_blocked_by_wall() {
+        let (mut map, index) = grid(&[]);
+        let i = map.idx(4, 5);
+        map.terrain[i] = TerrainKind::Wall;
+        let w = world(&map, &index, &[]);
+        let pull = pull_path(&w, GridPos::new(2, 5), GridPos::new(5, 5), 3);
+        assert_eq!(cells(&pull.path), vec![(3, 5)]);
+        assert_eq!(pull.collided, None);
+    }
+}
@@ -0,0 +1,656 @@
+// src/gameplay/combat/pipeline.rs
+//! 공격 1회 해석 순서 고정: 발사된 투사체(FiringMods, 호출 측에서 생성) → 투사체마다 명중(①) → 속성(②) → OnHit(③) → 전파(④: 관통/도탄/연쇄) 반복
+//! 속성은 투사체 명중(직접/전파)에만 부여, 폭발/충돌 같은 부가 피해에는 없음
+//! 속성 착탄 시 기존 상태이상과의 시너지 반응을 먼저 처리(synergy 모듈 규칙)
+//! CombatWorld 스냅샷 위에서 동작하는 순수 함수 — 결과는 AttackTrace 하나로 모아 반환.
+//!
+//! OnHit 순서 (투사체 명중 1건마다, 다음 전파보다 먼저)
+//! - 중력(당기기) → 폭발(every_hit) → 넉백  — 당긴 뒤 터뜨리는 콤보가 성립하도록
+//! - 폭발(최종 1회)만 예외: 전파가 모두 끝난 뒤 마지막 투사체 명중 지점에서
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::units::components::{
+    Attack, Element, Explode, FiringMods, GravityPull, Knockback, OnHitEffects, Propagation, Stun,
+};
+
+use super::{
+    attack::{DamageBreakdown, apply_damage, resolve_hit},
+    firing::Projectile,
+    onhit::{away_from, explosion_victims, pull_candidates, pull_path, push_path},
+    propagation::{chain_next, pierce_targets, ricochet_dirs, trace_ray},
+    resources::CombatConfig,
+    snapshot::CombatWorld,
+    statuses::{active_elements, apply_element, clear_element},
+    synergy::{Synergy, SynergyEffect, SynergyMatrix, spread_targets},
+    targeting::line_of_fire,
+};
+
+/// 히트 발생 경위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitCause {
+    /// 조준 타일의 첫 명중
+    Direct,
+    /// 관통 n번째(1부터)
+    Pierce { depth: u8 },
+    /// 도탄: 부모 히트(trace.hits 인덱스)에서 갈라진 branch번째 광선, depth = 누적 튕김 수(벽 반사 포함)
+    Ricochet { parent: usize, branch: u8, depth: u8 },
+    /// 연쇄 n번째 도약(1부터), 직전 히트에서 이어짐
+    Chain { jump: u8 },
+    /// 폭발 피해: origin = 폭발을 일으킨 투사체 히트(trace.hits 인덱스, 폭발 중심 = 그 위치)
+    Explosion { origin: usize },
+    /// 넉백 충돌 피해: origin = 넉백을 일으킨 투사체 히트
+    Impact { origin: usize },
+    /// 시너지 반응 피해: origin = 반응을 일으킨 투사체 히트
+    Reaction { origin: usize },
+}
+
+impl HitCause {
+    /// 투사체가 직접 맞힌 히트인지(폭발 등 부가 피해 제외)
+    #[inline]
+    pub fn is_projectile(self) -> bool {
+        !matches!(self, HitCause::Explosion { .. } | HitCause::Impact { .. } | HitCause::Reaction { .. })
+    }
+}
+
+/// 히트 1건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitRecord {
+    /// 이 히트를 낸 투사체(trace.projectiles 인덱스)
+    pub projectile: usize,
+    pub target: Entity,
+    pub at: GridPos,
+    pub cause: HitCause,
+    pub breakdown: DamageBreakdown,
+    /// 이 히트로 착탄한 속성(생존 대상에만, 반응으로 소모됐어도 기록)
+    pub element: Option<Element>,
+}
+
+/// 시너지 반응 1건
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SynergyRecord {
+    /// 반응을 일으킨 투사체 히트(trace.hits 인덱스)
+    pub origin: usize,
+    pub target: Entity,
+    pub at: GridPos,
+    /// 대상에 걸려 있던 속성
+    pub existing: Element,
+    /// 새로 착탄한 속성
+    pub incoming: Element,
+    pub synergy: Synergy,
+    /// 확산으로 속성이 번진 유닛(y → x 순)
+    pub spread: Vec<Entity>,
+}
+
+/// 위치 이동 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplacementKind {
+    Knockback,
+    /// 중력 당김 (충돌 피해 없음)
+    Pull,
+}
+
+/// 해석 중 유닛 위치 이동 1건 (뷰 애니메이션용 전체 경로)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Displacement {
+    pub target: Entity,
+    pub kind: DisplacementKind,
+    /// 이동을 일으킨 투사체 히트(trace.hits 인덱스)
+    pub origin: usize,
+    pub from: GridPos,
+    /// 지나간 칸(시작 칸 제외, 마지막 = 도착 칸). 바로 막혔으면 빈 Vec
+    pub path: Vec<GridPos>,
+    /// 다른 유닛과 충돌해 멈췄으면 그 유닛
+    pub collided: Option<Entity>,
+}
+
+impl Displacement {
+    #[inline]
+    pub fn to(&self) -> GridPos {
+        self.path.last().copied().unwrap_or(self.from)
+    }
+}
+
+/// 공격 1회의 전체 해석 결과 (로그/미리보기/VFX 공용)
+/// hits는 적용 순서 그대로
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttackTrace {
+    pub attacker: Entity,
+    pub from: GridPos,
+    pub at: GridPos,
+    pub dir: (i32, i32),
+    /// 발사된 투사체(발사 순서)
+    pub projectiles: Vec<Projectile>,
+    pub hits: Vec<HitRecord>,
+    /// 넉백/중력 등 위치 이동(발생 순서)
+    pub displacements: Vec<Displacement>,
+    /// 시너지 반응(발생 순서)
+    pub synergies: Vec<SynergyRecord>,
+}
+
+impl AttackTrace {
+    /// 체력에 들어간 피해 합
+    pub fn total_hp_damage(&self) -> i32 {
+        self.hits.iter().map(|h| h.breakdown.hp_damage).sum()
+    }
+
+    /// hits[i]에서 갈라져 나온 도탄 히트들의 인덱스 (트리 순회용)
+    pub fn children(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        self.hits.iter().enumerate().filter_map(move |(j, h)| match h.cause {
+            HitCause::Ricochet { parent, .. } if parent == i => Some(j),
+            _ => None,
+        })
+    }
+
+    /// 마지막 투사체 명중의 인덱스
+    pub fn last_projectile_hit(&self) -> Option<usize> {
+        self.hits.iter().rposition(|h| h.cause.is_projectile())
+    }
+
+    /// 이 유닛이 해당 투사체에 이미 맞았는지(전파 대상 중복 방지 — 다른 투사체는 별개)
+    pub fn struck(&self, projectile: usize, e: Entity) -> bool {
+        self.hits
+            .iter()
+            .any(|h| h.projectile == projectile && h.target == e && h.cause.is_projectile())
+    }
+
+    /// 속성이 한 번이라도 부여됐는지(임뷰 쿨다운 소모 기준)
+    pub fn element_triggered(&self) -> bool {
+        self.hits.iter().any(|h| h.element.is_some())
+    }
+
+    /// 이번 공격으로 죽은 유닛
+    pub fn kills(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.hits.iter().filter(|h| h.breakdown.killed).map(|h| h.target)
+    }
+}
+
+/// 공격 1회 입력 (공격자 스탯/룰 사본)
+/// at/dir은 원래 조준(투사체별 방향/착탄 칸은 발사 단계에서 생성)
+#[derive(Debug, Clone, Copy)]
+pub struct Shot {
+    pub attacker: Entity,
+    pub from: GridPos,
+    pub at: GridPos,
+    pub dir: (i32, i32),
+    pub attack: Attack,
+    pub propagation: Propagation,
+    pub on_hit: OnHitEffects,
+    pub firing: FiringMods,
+    /// 부여할 속성 (ElementalImbue가 쿨다운 중이면 None)
+    pub element: Option<Element>,
+}
+
+/// 공격 해석: 발사 순서대로 투사체마다 착탄 칸 명중 후 Propagation 규칙대로 이어서 명중
+/// - `projectiles`: 발사할 투사체(firing::generate_projectiles, 탄창 제한은 호출 측에서 반영)
+/// - `roll`: 투사체 히트마다 1회 호출되는 [0, 1) 치명타 난수 (미리보기는 || 1.0 으로 치명타 배제)
+/// - 착탄 칸에 살아 있는 유닛이 없는 투사체는 빗나감(히트 없음)
+pub fn resolve_attack(
+    world: &mut CombatWorld,
+    shot: &Shot,
+    projectiles: Vec<Projectile>,
+    config: &CombatConfig,
+    synergy: &SynergyMatrix,
+    roll: &mut impl FnMut() -> f32,
+) -> AttackTrace {
+    let mut r = Resolver {
+        world,
+        shot,
+        config,
+        synergy,
+        roll,
+        current: 0,
+        trace: AttackTrace {
+            attacker: shot.attacker,
+            from: shot.from,
+            at: shot.at,
+            dir: shot.dir,
+            projectiles,
+            hits: Vec::new(),
+            displacements: Vec::new(),
+            synergies: Vec::new(),
+        },
+    };
+    for i in 0..r.trace.projectiles.len() {
+        r.current = i;
+        r.fire();
+    }
+    r.trace
+}
+
+/// 해석 진행 상태(스냅샷 + 누적 trace)
+struct Resolver<'r, 'a, R> {
+    world: &'r mut CombatWorld<'a>,
+    shot: &'r Shot,
+    config: &'r CombatConfig,
+    synergy: &'r SynergyMatrix,
+    roll: &'r mut R,
+    /// 지금 해석 중인 투사체(trace.projectiles 인덱스)
+    current: usize,
+    trace: AttackTrace,
+}
+
+impl<R: FnMut() -> f32> Resolver<'_, '_, R> {
+    #[inline]
+    fn projectile(&self) -> Projectile {
+        self.trace.projectiles[self.current]
+    }
+
+    /// 투사체 1발: 사선 판정 → 직접 명중 → 전파 → 최종 폭발
+    fn fire(&mut self) {
+        // 사선: 직사는 첫 벽/유닛에서 멈춤(앞선 투사체로 죽은 유닛은 막지 않음), 곡사는 착탄 칸만
+        let planned = self.projectile().at;
+        let world = &*self.world;
+        let lof = line_of_fire(world.map, |p| world.unit_at(p), self.shot.from, planned, self.shot.firing.lobbed);
+        let at = lof.impact(planned);
+        self.trace.projectiles[self.current].at = at;
+
+        // ① 직접 명중
+        let Some(first) = self.world.unit_at(at) else { return; };
+        let Some(root) = self.strike(first, at, HitCause::Direct) else { return; };
+
+        // ④ 전파
+        match self.shot.propagation {
+            Propagation::None => {}
+            Propagation::Pierce { times } => self.pierce(times),
+            Propagation::Ricochet { times, branches } => self.ricochet(root, times, branches),
+            Propagation::Chain { jumps, radius, diagonal } => self.chain(jumps, radius, diagonal),
+        }
+
+        // ③ 최종 명중 1회 폭발 (이 투사체의 마지막 명중)
+        let final_explode = self.shot.on_hit.explode.filter(|x| !x.every_hit);
+        if let (Some(explode), Some(last)) = (final_explode, self.trace.last_projectile_hit()) {
+            self.explode(&explode, last);
+        }
+    }
+
+    /// 투사체 명중 1건 + 매 히트 OnHit. 기록된 히트 인덱스 반환
+    fn strike(&mut self, target: Entity, at: GridPos, cause: HitCause) -> Option<usize> {
+        let i = self.hit(target, at, cause)?;
+        if let Some(gravity) = self.shot.on_hit.gravity {
+            self.gravity(&gravity, i);
+        }
+        if let Some(explode) = self.shot.on_hit.explode.filter(|x| x.every_hit) {
+            self.explode(&explode, i);
+        }
+        if let Some(knockback) = self.shot.on_hit.knockback {
+            self.knockback(&knockback, i);
+        }
+        Some(i)
+    }
+
+    /// 관통: 첫 명중 칸 너머로 직선 진행
+    /// - 한 칸씩 다시 찾음(앞선 폭발로 죽은 유닛은 관통 횟수에 포함하지 않음)
+    fn pierce(&mut self, times: u8) {
+        let Projectile { at: mut from, dir, .. } = self.projectile();
+        for depth in 1..=times {
+            let skip = |e: Entity| self.trace.struck(self.current, e);
+            let Some((e, p)) = pierce_targets(self.world, from, dir, 1, skip).pop() else { return; };
+            self.strike(e, p, HitCause::Pierce { depth });
+            from = p;
+        }
+    }
+
+    /// 도탄: 명중할 때마다 branches개 광선으로 분기, 총 times회까지 튕김
+    /// - 적용 순서: 너비 우선(분기 세대 → 부모 히트 순 → 분기 순) — 트리가 항상 같은 순서로 펼쳐짐
+    /// - 유닛 명중 시 분기 1회 = 튕김 1회, 벽/맵 끝 반사도 튕김 1회 소모
+    /// - 광선 1개의 비행 거리는 공격 사거리까지
+    /// - 공격자와 이미 맞은 유닛은 통과(한 공격에서 유닛당 1회 명중)
+    fn ricochet(&mut self, root: usize, times: u8, branches: u8) {
+        if times == 0 { return; }
+
+        // (부모 히트 인덱스, 진행 방향, 부모까지 쓴 튕김 수)
+        let mut queue = VecDeque::from([(root, self.projectile().dir, 0u8)]);
+        while let Some((parent, incoming, used)) = queue.pop_front() {
+            if used >= times { continue; }
+            let origin = self.trace.hits[parent].at;
+            for (b, dir) in ricochet_dirs(incoming, branches).into_iter().enumerate() {
+                let skip = |e: Entity| e == self.shot.attacker || self.trace.struck(self.current, e);
+                // 분기 자체가 튕김 1회
+                let ray = trace_ray(self.world, origin, dir, self.shot.attack.range, times - used - 1, skip);
+                let Some(ray) = ray else { continue; };
+                let depth = times - ray.bounces_left;
+                let cause = HitCause::Ricochet { parent, branch: b as u8, depth };
+                if let Some(i) = self.strike(ray.target, ray.at, cause) {
+                    queue.push_back((i, ray.dir, depth));
+                }
+            }
+        }
+    }
+
+    /// 연쇄: 직전 히트 위치에서 가장 가까운, 아직 안 맞은 적에게 jumps회까지 도약
+    /// - 적 = 공격자와 다른 팀. 대상이 없으면 그 자리에서 종료
+    fn chain(&mut self, jumps: u8, radius: u8, diagonal: bool) {
+        let Some(team) = self.world.unit(self.shot.attacker).map(|u| u.team) else { return; };
+        for jump in 1..=jumps {
+            let Some(last) = self.trace.last_projectile_hit().map(|i| self.trace.hits[i].at) else { return; };
+            let skip = |e: Entity| self.trace.struck(self.current, e);
+            let Some((e, p)) = chain_next(self.world, last, team, radius, diagonal, skip) else { return; };
+            self.strike(e, p, HitCause::Chain { jump });
+        }
+    }
+
+    /// 폭발: hits[origin] 위치 중심 반경 내 유닛에 bonus_damage (치명타 없음)
+    fn explode(&mut self, explode: &Explode, origin: usize) {
+        let center = self.trace.hits[origin].at;
+        let Some(team) = self.world.unit(self.shot.attacker).map(|u| u.team) else { return; };
+        let victims = explosion_victims(
+            self.world,
+            center,
+            explode.radius,
+            self.shot.attacker,
+            team,
+            self.config.explosion_friendly_fire,
+        );
+        for (e, p) in victims {
+            self.extra(e, p, explode.bonus_damage, HitCause::Explosion { origin });
+        }
+    }
+
+    /// 중력: hits[origin] 위치로 range 내 유닛을 가까운 순서대로 최대 strength칸 당김
+    /// - 순서대로 스냅샷에 반영하므로 뒤에 당겨지는 유닛은 앞 유닛이 차지한 칸 앞에서 멈춤
+    fn gravity(&mut self, gravity: &GravityPull, origin: usize) {
+        if gravity.strength == 0 { return; }
+        let center = self.trace.hits[origin].at;
+        for (e, from) in pull_candidates(self.world, center, gravity.range, self.shot.attacker) {
+            let pull = pull_path(self.world, from, center, gravity.strength);
+            let Some(&to) = pull.path.last() else { continue; };
+            self.world.move_unit(e, to);
+            self.trace.displacements.push(Displacement {
+                target: e,
+                kind: DisplacementKind::Pull,
+                origin,
+                from,
+                path: pull.path,
+                collided: pull.collided,
+            });
+        }
+    }
+
+    /// 넉백: hits[origin] 대상을 공격자 반대 방향으로 최대 distance칸 밀기
+    /// - 다른 유닛과 충돌하면 양쪽 모두 impact_damage
+    /// - 이미 죽은 대상은 밀지 않음
+    fn knockback(&mut self, knockback: &Knockback, origin: usize) {
+        let target = self.trace.hits[origin].target;
+        let Some(from) = self.world.unit(target).filter(|u| !u.stats.is_dead()).map(|u| u.pos) else { return; };
+        let dir = away_from(self.shot.from, from);
+        if dir == (0, 0) || knockback.distance == 0 { return; }
+
+        let push = push_path(self.world, from, dir, knockback.distance);
+        if let Some(&to) = push.path.last() {
+            self.world.move_unit(target, to);
+        }
+        if let Some(other) = push.collided {
+            let at = self.world.unit(target).map_or(from, |u| u.pos);
+            self.extra(target, at, knockback.impact_damage, HitCause::Impact { origin });
+            if let Some(other_at) = self.world.unit(other).map(|u| u.pos) {
+                self.extra(other, other_at, knockback.impact_damage, HitCause::Impact { origin });
+            }
+        }
+        self.trace.displacements.push(Displacement {
+            target,
+            kind: DisplacementKind::Knockback,
+            origin,
+            from,
+            path: push.path,
+            collided: push.collided,
+        });
+    }
+
+    /// 부가 피해 1건(치명타 없음): 폭발/충돌 등. 죽은 유닛은 무시
+    fn extra(&mut self, target: Entity, at: GridPos, amount: i32, cause: HitCause) {
+        let Some(unit) = self.world.unit_mut(target).filter(|u| !u.stats.is_dead()) else { return; };
+        let breakdown = apply_damage(unit.stats, amount, amount, false);
+        unit.stats = breakdown.after;
+        self.trace.hits.push(HitRecord { projectile: self.current, target, at, cause, breakdown, element: None });
+    }
+
+    /// 투사체 히트 1건 적용: 스냅샷 스탯 갱신 + trace 기록
+    fn hit(&mut self, target: Entity, at: GridPos, cause: HitCause) -> Option<usize> {
+        let unit = self.world.unit_mut(target).filter(|u| !u.stats.is_dead())?;
+        let breakdown = resolve_hit(&self.shot.attack, unit.stats, (self.roll)());
+        unit.stats = breakdown.after;
+        // ② 속성: 살아남은 대상에만
+        let element = self.shot.element.filter(|_| !unit.stats.is_dead());
+        self.trace.hits.push(HitRecord { projectile: self.current, target, at, cause, breakdown, element });
+        let i = self.trace.hits.len() - 1;
+        if let Some(el) = element {
+            self.imbue(i, el);
+        }
+        Some(i)
+    }
+
+    /// 속성 착탄: 기존 상태이상과 반응(고정 순서) → 소모되지 않았으면 부여
+    fn imbue(&mut self, origin: usize, incoming: Element) {
+        let target = self.trace.hits[origin].target;
+        let Some(unit) = self.world.unit(target) else { return; };
+        let reactions: Vec<(Element, Synergy)> = active_elements(&unit.statuses)
+            .filter_map(|existing| self.synergy.get(existing, incoming).map(|s| (existing, s.clone())))
+            .collect();
+
+        let mut consumed = false;
+        for (existing, synergy) in reactions {
+            consumed |= synergy.consume;
+            self.react(origin, existing, incoming, synergy);
+        }
+        if consumed { return; }
+        if let Some(unit) = self.world.unit_mut(target) {
+            apply_element(&mut unit.statuses, incoming, &self.config.status);
+        }
+    }
+
+    /// 시너지 반응 1건 적용 + 기록
+    fn react(&mut self, origin: usize, existing: Element, incoming: Element, synergy: Synergy) {
+        let HitRecord { target, at, .. } = self.trace.hits[origin];
+        if let Some(unit) = self.world.unit_mut(target).filter(|_| synergy.consume) {
+            clear_element(&mut unit.statuses, existing);
+        }
+
+        let mut spread = Vec::new();
+        match synergy.effect {
+            SynergyEffect::Damage { amount } => self.extra(target, at, amount, HitCause::Reaction { origin }),
+            SynergyEffect::Spread { element, radius } => {
+                let ff = self.config.spread_friendly_fire;
+                for (e, _) in spread_targets(self.world, at, radius, target, self.shot.attacker, ff) {
+                    if let Some(unit) = self.world.unit_mut(e) {
+                        apply_element(&mut unit.statuses, element, &self.config.status);
+                        spread.push(e);
+                    }
+                }
+            }
+            SynergyEffect::Stun { turns } => {
+                if let Some(unit) = self.world.unit_mut(target) {
+                    let turns = unit.statuses.stun.map_or(0, |s| s.turns).max(turns);
+                    unit.statuses.stun = Some(Stun { turns });
+                }
+            }
+        }
+        self.trace.synergies.push(SynergyRecord { origin, target, at, existing, incoming, synergy, spread });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::combat::firing::generate_projectiles;
+    use crate::gameplay::combat::resources::FriendlyFire;
+    use crate::gameplay::combat::snapshot::UnitSnap;
+    use crate::gameplay::combat::synergy::SynergyTable;
+    use crate::gameplay::tiles::components::TerrainKind;
+    use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+    use crate::gameplay::units::components::{Poison, Stats, TeamId};
+
+    /// (id, x, y, team) 유닛을 10x10 평지에 배치
+    fn grid(units: &[(u32, u32, u32, u8)]) -> (BaseTileMap, GridIndex) {
+        let map = BaseTileMap::filled(10, 10, TerrainKind::Ground);
+        let mut index = GridIndex::new(10, 10);
+        for &(id, x, y, _) in units {
+            index.place(x, y, Entity::from_raw(id)).unwrap();
+        }
+        (map, index)
+    }
+
+    fn world<'a>(map: &'a BaseTileMap, index: &'a GridIndex, units: &[(u32, u32, u32, u8)]) -> CombatWorld<'a> {
+        let mut w = CombatWorld::new(map, index);
+        for &(id, x, y, team) in units {
+            let snap = UnitSnap {
+                pos: GridPos::new(x, y),
+                team: TeamId(team),
+                stats: Stats::new(10, 0),
+                statuses: Default::default(),
+            };
+            w.insert(Entity::from_raw(id), snap);
+        }
+        w
+    }
+
+    /// 1번 유닛이 from → at 으로 쏘는 치명타 없는 공격
+    fn shot(from: (u32, u32), at: (u32, u32)) -> Shot {
+        let (from, at) = (GridPos::new(from.0, from.1), GridPos::new(at.0, at.1));
+        let attack = Attack { crit_chance: 0.0, range: 5, ..Attack::default() };
+        let (dir, _) = attack.aim_line(from, at).unwrap();
+        Shot {
+            attacker: Entity::from_raw(1),
+            from,
+            at,
+            dir,
+            attack,
+            propagation: Propagation::None,
+            on_hit: OnHitEffects::default(),
+            firing: FiringMods::default(),
+            element: None,
+        }
+    }
+
+    /// 조준대로 투사체를 생성해 해석 (치명타 없음)
+    fn fire(w: &mut CombatWorld, s: &Shot, config: &CombatConfig, synergy: &SynergyMatrix) -> AttackTrace {
+        let (_, distance) = s.attack.aim_line(s.from, s.at).unwrap();
+        let projectiles = generate_projectiles(w.map, s.from, s.at, s.dir, distance, &s.firing, s.attack.dirs);
+        resolve_attack(w, s, projectiles, config, synergy, &mut || 1.0)
+    }
+
+    fn explosion_ids(trace: &AttackTrace) -> Vec<u32> {
+        trace
+            .hits
+            .iter()
+            .filter(|h| matches!(h.cause, HitCause::Explosion { .. }))
+            .map(|h| h.target.index())
+            .collect()
+    }
+
+    #[test]
+    fn explosion_hits_allies_per_policy() {
+        // 1 공격자(팀0) → 2 적(팀1), 3 아군(팀0)은 폭발 반경 안
+        let units = [(1, 4, 5, 0), (2, 5, 5, 1), (3, 5, 6, 0)];
+        let (map, index) = grid(&units);
+        let mut s = shot((4, 5), (5, 5));
+        s.on_hit.explode = Some(Explode { radius: 1, bonus_damage: 2, every_hit: false });
+
+        let cases = [
+            (FriendlyFire::EnemiesOnly, vec![2]),
+            (FriendlyFire::ExceptSelf, vec![2, 3]),
+            (FriendlyFire::All, vec![1, 2, 3]),
+        ];
+        for (ff, expected) in cases {
+            let config = CombatConfig { explosion_friendly_fire: ff, ..CombatConfig::default() };
+            let mut w = world(&map, &index, &units);
+            let trace = fire(&mut w, &s, &config, &SynergyMatrix::default());
+            assert_eq!(explosion_ids(&trace), expected, "{ff:?}");
+        }
+    }
+
+    #[test]
+    fn spread_reaches_allies_per_policy() {
+        // 중독된 2(팀1)에 화염 착탄 → Blaze 확산: 4 적(팀1), 3 아군(팀0)이 반경 안
+        let units = [(1, 2, 5, 0), (2, 5, 5, 1), (3, 5, 6, 0), (4, 6, 5, 1)];
+        let (map, index) = grid(&units);
+        let table: SynergyTable = ron::from_str(include_str!("../../../assets/rules/elemental.synergy.ron")).unwrap();
+        let synergy = SynergyMatrix::from(&table);
+        let mut s = shot((2, 5), (5, 5));
+        s.element = Some(Element::Fire);
+
+        let cases = [(FriendlyFire::EnemiesOnly, vec![4]), (FriendlyFire::ExceptSelf, vec![4, 3])];
+        for (ff, expected) in cases {
+            let config = CombatConfig { spread_friendly_fire: ff, ..CombatConfig::default() };
+            let mut w = world(&map, &index, &units);
+            w.unit_mut(Entity::from_raw(2)).unwrap().statuses.poison = Some(Poison { stacks: 1, turns: 3 });
+            let trace = fire(&mut w, &s, &config, &synergy);
+            let spread: Vec<u32> = trace.synergies[0].spread.iter().map(|e| e.index()).collect();
+            assert_eq!(spread, expected, "{ff:?}");
+        }
+    }
+
+    #[test]
+    fn knockback_into_unit_damages_both_and_records_displacement() {
+        // 1 → 2를 밀면 한 칸 뒤 3과 충돌
+        let units = [(1, 2, 5, 0), (2, 4, 5, 1), (3, 6, 5, 1)];
+        let (map, index) = grid(&units);
+        let mut s = shot((2, 5), (4, 5));
+        s.on_hit.knockback = Some(Knockback { distance: 3, impact_damage: 2 });
+        let mut w = world(&map, &index, &units);
+        let trace = fire(&mut w, &s, &CombatConfig::default(), &SynergyMatrix::default());
+
+        let impacts: Vec<(u32, i32)> = trace
+            .hits
+            .iter()
+            .filter(|h| matches!(h.cause, HitCause::Impact { origin: 0 }))
+            .map(|h| (h.target.index(), h.breakdown.hp_damage))
+            .collect();
+        assert_eq!(impacts, vec![(2, 2), (3, 2)]);
+
+        let d = &trace.displacements[0];
+        assert_eq!((d.kind, d.target.index()), (DisplacementKind::Knockback, 2));
+        assert_eq!(d.to(), GridPos::new(5, 5));
+        assert_eq!(d.collided, Some(Entity::from_raw(3)));
+        assert_eq!(w.unit(Entity::from_raw(2)).unwrap().pos, GridPos::new(5, 5));
+    }
+
+    #[test]
+    fn gravity_pulls_nearest_first_and_later_units_queue_behind() {
+        // 2에 명중 → 3(2칸), 4(3칸)가 같은 줄에서 당겨짐: 3이 먼저 붙고 4는 그 뒤에서 멈춤
+        let units = [(1, 5, 2, 0), (2, 5, 5, 1), (3, 7, 5, 1), (4, 8, 5, 1)];
+        let (map, index) = grid(&units);
+        let mut s = shot((5, 2), (5, 5));
+        s.on_hit.gravity = Some(GravityPull { range: 3, strength: 3 });
+        let mut w = world(&map, &index, &units);
+        let trace = fire(&mut w, &s, &CombatConfig::default(), &SynergyMatrix::default());
+
+        let pulls: Vec<(u32, GridPos)> = trace
+            .displacements
+            .iter()
+            .filter(|d| d.kind == DisplacementKind::Pull)
+            .map(|d| (d.target.index(), d.to()))
+            .collect();
+        assert_eq!(pulls, vec![(3, GridPos::new(6, 5)), (4, GridPos::new(7, 5))]);
+        // 당김은 충돌 피해 없음
+        assert!(trace.hits.iter().all(|h| !matches!(h.cause, HitCause::Impact { .. })));
+        assert_eq!(w.unit(Entity::from_raw(4)).unwrap().pos, GridPos::new(7, 5));
+    }
+
+    #[test]
+    fn consuming_reaction_clears_both_elements_and_deals_bonus_damage() {
+        // 화염이 걸린 2에 빙결 착탄 → Melt: 추가 피해 4, 화염 해제, 빙결 미부여
+        let units = [(1, 2, 5, 0), (2, 5, 5, 1)];
+        let (map, index) = grid(&units);
+        let table: SynergyTable = ron::from_str(include_str!("../../../assets/rules/elemental.synergy.ron")).unwrap();
+        let mut s = shot((2, 5), (5, 5));
+        s.element = Some(Element::Ice);
+        let mut w = world(&map, &index, &units);
+        let target = Entity::from_raw(2);
+        apply_element(&mut w.unit_mut(target).unwrap().statuses, Element::Fire, &Default::default());
+
+        let trace = fire(&mut w, &s, &CombatConfig::default(), &SynergyMatrix::from(&table));
+        let reaction: Vec<i32> = trace
+            .hits
+            .iter()
+            .filter(|h| matches!(h.cause, HitCause::Reaction { origin: 0 }))
+            .map(|h| h.breakdown.hp_damage)
+            .collect();
+        assert_eq!(reaction, vec![4]);
+        assert_eq!(trace.synergies[0].synergy.name, "Melt");
+        let statuses = w.unit(target).unwrap().statuses;
+        assert_eq!((statuses.burning, statuses.chill), (None, None));
+    }
+}
@@ -0,0 +1,73 @@
+// src/gameplay/combat/plugin.rs
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+use crate::app::state::AppState;
+use crate::gameplay::commands::CommandsSet;
+use crate::gameplay::turns::TurnSet;
+
+use super::{
+    events::{AttackApplied, DamageApplied, ElementApplied, KnockbackApplied, SynergyTriggered},
+    resources::{CombatConfig, CombatRng},
+    rules::{CombatRules, CombatRulesHandle},
+    synergy::{SynergyMatrix, SynergyTable, SynergyTableHandle},
+    systems::{
+        apply_attack_damage, apply_combat_rules, apply_synergy_table, enforce_status_budgets, load_combat_rules,
+        load_synergy_table, publish_element_events, tick_status_effects,
+    },
+};
+
+/// 전투 해석 순서 정의
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CombatSet {
+    /// 확정된 공격 → 피해 계산/적용
+    Resolve,
+}
+
+/// 전투 코어: 피해 공식/치명타/쉴드 + 결과 이벤트
+pub struct CombatPlugin;
+
+impl Plugin for CombatPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CombatRng>()
+            .init_resource::<CombatConfig>()
+            // 전투 규칙 (*.rules.ron)
+            .add_plugins(RonAssetPlugin::<CombatRules>::new(&["rules.ron"]))
+            .init_resource::<CombatRulesHandle>()
+            // 속성 시너지 반응표 (*.synergy.ron)
+            .add_plugins(RonAssetPlugin::<SynergyTable>::new(&["synergy.ron"]))
+            .init_resource::<SynergyMatrix>()
+            .init_resource::<SynergyTableHandle>()
+            .add_event::<DamageApplied>()
+            .add_event::<AttackApplied>()
+            .add_event::<KnockbackApplied>()
+            .add_event::<ElementApplied>()
+            .add_event::<SynergyTriggered>()
+            .add_systems(Startup, (load_combat_rules, load_synergy_table))
+            .add_systems(Update, (apply_combat_rules, apply_synergy_table))
+            // 순서: 명령 실행(Execute) → 전투 해석(Resolve)
+            .configure_sets(Update, CombatSet::Resolve.after(CommandsSet::Execute))
+            .add_systems(
+                Update,
+                (apply_attack_damage, publish_element_events)
+                    .chain()
+                    .in_set(CombatSet::Resolve)
+                    .run_if(in_state(AppState::Battle)),
+            )
+            // 상태이상: 페이즈 시작(예산 리셋 직후) 틱, 부여 직후 예산 제한
+            .add_systems(
+                Update,
+                tick_status_effects
+                    .after(TurnSet::Begin)
+                    .before(CommandsSet::Execute)
+                    .run_if(in_state(AppState::Battle)),
+            )
+            .add_systems(
+                Update,
+                enforce_status_budgets
+                    .after(CombatSet::Resolve)
+                    .run_if(in_state(AppState::Battle)),
+            );
+    }
+}
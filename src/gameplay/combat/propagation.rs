@@ -0,0 +1,313 @@
+// src/gameplay/combat/propagation.rs
+//! ④ Propagation: 첫 명중 이후 투사체가 어디로 이어지는지(기하만 계산, 피해는 pipeline)
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::units::components::TeamId;
+
+use super::snapshot::CombatWorld;
+
+/// 관통: `from` 다음 칸부터 dir 직선으로 진행하며 점유 칸을 최대 `times`개 수집
+/// - 벽/맵 끝에서 정지
+/// - 빈 칸은 그냥 통과, 이미 죽은 유닛 칸은 횟수에 포함하지 않음
+/// - `skip`이 true인 유닛(이미 맞은 유닛 — 넉백으로 앞으로 밀려난 경우 등)도 통과
+pub fn pierce_targets(
+    world: &CombatWorld,
+    from: GridPos,
+    dir: (i32, i32),
+    times: u8,
+    skip: impl Fn(Entity) -> bool,
+) -> Vec<(Entity, GridPos)> {
+    let mut out = Vec::with_capacity(times as usize);
+    let mut p = from;
+    while out.len() < times as usize {
+        let Some(next) = world.step(p, dir) else { break; };
+        if world.is_wall(next) { break; }
+        if let Some(e) = world.unit_at(next).filter(|&e| !skip(e)) {
+            out.push((e, next));
+        }
+        p = next;
+    }
+    out
+}
+
+/// 연쇄: `from`에서 가장 가까운 적 1기
+/// - 거리: diagonal이면 체비셰프, 아니면 맨해튼. radius 이내만
+/// - `team`과 다른 팀의 살아 있는 유닛 중 `skip`이 false인 것만
+/// - 동률: 거리 → y → x 순 (위치는 유닛마다 유일하므로 항상 하나로 결정)
+pub fn chain_next(
+    world: &CombatWorld,
+    from: GridPos,
+    team: TeamId,
+    radius: u8,
+    diagonal: bool,
+    skip: impl Fn(Entity) -> bool,
+) -> Option<(Entity, GridPos)> {
+    let dist = |p: GridPos| if diagonal { from.chebyshev(p) } else { from.manhattan(p) };
+    world
+        .alive_units()
+        .filter(|(e, u)| u.team != team && !skip(*e))
+        .map(|(e, u)| (dist(u.pos), u.pos.y, u.pos.x, e, u.pos))
+        .filter(|&(d, ..)| d > 0 && d <= radius as u32)
+        .min_by_key(|&(d, y, x, ..)| (d, y, x))
+        .map(|(.., e, p)| (e, p))
+}
+
+/// 8방향 고리(반시계 순) — 도탄 분기 방향 회전용
+const RING8: [(i32, i32); 8] = [
+    (1, 0), (1, 1), (0, 1), (-1, 1),
+    (-1, 0), (-1, -1), (0, -1), (1, -1),
+];
+
+/// dir을 45° 단위로 k번 회전 (양수 = 반시계)
+pub(crate) fn rotate45(dir: (i32, i32), k: i32) -> (i32, i32) {
+    let Some(i) = RING8.iter().position(|&d| d == dir) else { return dir; };
+    RING8[(i as i32 + k).rem_euclid(8) as usize]
+}
+
+/// 도탄 분기 방향 (결정적)
+/// - 진행 방향 기준 좌우 대칭으로 45° 간격 펼침, 왼쪽(반시계) 먼저
+/// - 홀수면 정면 포함: 1 → [정면], 3 → [정면, 좌45, 우45]
+/// - 짝수면 정면 제외: 2 → [좌45, 우45], 4 → [좌45, 우45, 좌90, 우90]
+/// - 8 이상이면 전 방향
+pub fn ricochet_dirs(incoming: (i32, i32), branches: u8) -> Vec<(i32, i32)> {
+    // 정면 → 좌/우 45° → 좌/우 90° → 좌/우 135° → 후면
+    const ORDER: [i32; 8] = [0, 1, -1, 2, -2, 3, -3, 4];
+    let n = branches.min(8) as usize;
+    let picked = if n % 2 == 1 || n == 8 { &ORDER[..n] } else { &ORDER[1..=n] };
+    picked.iter().map(|&k| rotate45(incoming, k)).collect()
+}
+
+/// 벽/맵 끝 반사: p에서 dir로 더 못 갈 때 새 방향
+/// - 직선: 되돌아감
+/// - 대각: 막힌 축만 뒤집음(모서리/양쪽 막힘이면 둘 다)
+pub fn reflect(world: &CombatWorld, p: GridPos, dir: (i32, i32)) -> (i32, i32) {
+    let (dx, dy) = dir;
+    if dx == 0 || dy == 0 { return (-dx, -dy); }
+    let blocked = |d: (i32, i32)| world.step(p, d).is_none_or(|q| world.is_wall(q));
+    match (blocked((dx, 0)), blocked((0, dy))) {
+        (true, false) => (-dx, dy),
+        (false, true) => (dx, -dy),
+        _ => (-dx, -dy),
+    }
+}
+
+/// 도탄 광선 1개의 명중 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RayHit {
+    pub target: Entity,
+    pub at: GridPos,
+    /// 명중 시점의 진행 방향(분기 기준)
+    pub dir: (i32, i32),
+    /// 남은 튕김 횟수(벽 반사로 소모된 뒤)
+    pub bounces_left: u8,
+}
+
+/// 광선 1개 추적: origin 다음 칸부터 최대 `max_len`칸 진행
+/// - 벽/맵 끝: 튕김 1회 소모하고 반사, 남은 튕김이 없으면 소멸
+/// - `skip`이 true인 유닛(공격자/이미 맞은 유닛)은 통과
+pub fn trace_ray(
+    world: &CombatWorld,
+    origin: GridPos,
+    dir: (i32, i32),
+    max_len: u32,
+    mut bounces_left: u8,
+    skip: impl Fn(Entity) -> bool,
+) -> Option<RayHit> {
+    let mut p = origin;
+    let mut d = dir;
+    let mut travelled = 0;
+    while travelled < max_len {
+        match world.step(p, d).filter(|&q| !world.is_wall(q)) {
+            None => {
+                if bounces_left == 0 { return None; }
+                bounces_left -= 1;
+                d = reflect(world, p, d);
+            }
+            Some(next) => {
+                p = next;
+                travelled += 1;
+                if let Some(e) = world.unit_at(p).filter(|&e| !skip(e)) {
+                    return Some(RayHit { target: e, at: p, dir: d, bounces_left });
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::combat::snapshot::UnitSnap;
+    use crate::gameplay::tiles::components::TerrainKind;
+    use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+    use crate::gameplay::units::components::Stats;
+
+    const ALLY: TeamId = TeamId(0);
+
+    /// (id, x, y, team) 유닛을 10x10 평지에 배치
+    fn grid(units: &[(u32, u32, u32, u8)]) -> (BaseTileMap, GridIndex) {
+        let map = BaseTileMap::filled(10, 10, TerrainKind::Ground);
+        let mut index = GridIndex::new(10, 10);
+        for &(id, x, y, _) in units {
+            index.place(x, y, Entity::from_raw(id)).unwrap();
+        }
+        (map, index)
+    }
+
+    fn world<'a>(map: &'a BaseTileMap, index: &'a GridIndex, units: &[(u32, u32, u32, u8)]) -> CombatWorld<'a> {
+        let mut w = CombatWorld::new(map, index);
+        for &(id, x, y, team) in units {
+            let snap = UnitSnap {
+                pos: GridPos::new(x, y),
+                team: TeamId(team),
+                stats: Stats::new(10, 0),
+                statuses: Default::default(),
+            };
+            w.insert(Entity::from_raw(id), snap);
+        }
+        w
+    }
+
+    /// 맵에 벽 칸 찍기
+    fn walls(map: &mut BaseTileMap, cells: &[(u32, u32)]) {
+        for &(x, y) in cells {
+            let i = map.idx(x, y);
+            map.terrain[i] = TerrainKind::Wall;
+        }
+    }
+
+    fn ids(hits: &[(Entity, GridPos)]) -> Vec<u32> {
+        hits.iter().map(|(e, _)| e.index()).collect()
+    }
+
+    /// alive_units는 HashMap 순회 — 월드를 여러 번 새로 만들어도 같은 결과여야 함
+    fn pick(units: &[(u32, u32, u32, u8)], diagonal: bool, skip: &[u32]) -> Option<u32> {
+        let (map, index) = grid(units);
+        let mut seen = None;
+        for _ in 0..32 {
+            let w = world(&map, &index, units);
+            let got = chain_next(&w, GridPos::new(5, 5), ALLY, 3, diagonal, |e| skip.contains(&e.index()))
+                .map(|(e, _)| e.index());
+            if let Some(prev) = seen {
+                assert_eq!(prev, got, "tie-break must not depend on iteration order");
+            }
+            seen = Some(got);
+        }
+        seen.flatten()
+    }
+
+    #[test]
+    fn manhattan_tie_prefers_lower_y_then_x() {
+        // 모두 맨해튼 거리 2: (7,5) (3,5) (5,3) → y가 가장 작은 (5,3)
+        let units = [(1, 5, 5, 0), (2, 7, 5, 1), (3, 3, 5, 1), (4, 5, 3, 1)];
+        assert_eq!(pick(&units, false, &[]), Some(4));
+        // y도 같으면 x가 작은 쪽: (7,5) vs (3,5)
+        let units = [(1, 5, 5, 0), (2, 7, 5, 1), (3, 3, 5, 1)];
+        assert_eq!(pick(&units, false, &[]), Some(3));
+    }
+
+    #[test]
+    fn chebyshev_tie_prefers_lower_y_then_x() {
+        // 체비셰프 거리 1 동률: (6,6) (4,6) → y 같음 → x가 작은 (4,6). (5,7)은 거리 2
+        let units = [(1, 5, 5, 0), (2, 6, 6, 1), (3, 4, 6, 1), (4, 5, 7, 1)];
+        assert_eq!(pick(&units, true, &[]), Some(3));
+        // 체비셰프 거리 2 동률: (7,7) (3,7) (7,3) → y 최소 (7,3)
+        let units = [(1, 5, 5, 0), (2, 7, 7, 1), (3, 3, 7, 1), (4, 7, 3, 1)];
+        assert_eq!(pick(&units, true, &[]), Some(4));
+    }
+
+    #[test]
+    fn skip_excludes_already_hit() {
+        // 가장 가까운 (5,4)는 이미 맞음 → 다음 (5,7), 둘 다 건너뛰면 없음(반경 밖 제외)
+        let units = [(1, 5, 5, 0), (2, 5, 4, 1), (3, 5, 7, 1), (4, 5, 9, 1)];
+        assert_eq!(pick(&units, false, &[]), Some(2));
+        assert_eq!(pick(&units, false, &[2]), Some(3));
+        assert_eq!(pick(&units, false, &[2, 3]), None);
+    }
+
+    #[test]
+    fn same_team_is_never_chained() {
+        let units = [(1, 5, 5, 0), (2, 5, 6, 0), (3, 5, 8, 1)];
+        assert_eq!(pick(&units, false, &[]), Some(3));
+    }
+
+    #[test]
+    fn pierce_passes_empty_and_dead_cells_and_stops_at_wall() {
+        // (3,5) (5,5 사망) (6,5) | 벽 (8,5) | (9,5)
+        let units = [(2, 3, 5, 1), (3, 5, 5, 1), (4, 6, 5, 1), (5, 9, 5, 1)];
+        let (mut map, index) = grid(&units);
+        walls(&mut map, &[(8, 5)]);
+        let mut w = world(&map, &index, &units);
+        w.unit_mut(Entity::from_raw(3)).unwrap().stats.hp = 0;
+
+        let from = GridPos::new(2, 5);
+        assert_eq!(ids(&pierce_targets(&w, from, (1, 0), 5, |_| false)), [2, 4]);
+        assert_eq!(ids(&pierce_targets(&w, from, (1, 0), 1, |_| false)), [2]);
+        assert_eq!(ids(&pierce_targets(&w, from, (1, 0), 5, |e| e.index() == 2)), [4]);
+    }
+
+    fn ray(
+        map: &BaseTileMap,
+        index: &GridIndex,
+        units: &[(u32, u32, u32, u8)],
+        origin: (u32, u32),
+        dir: (i32, i32),
+        bounces: u8,
+    ) -> Option<RayHit> {
+        let w = world(map, index, units);
+        trace_ray(&w, GridPos::new(origin.0, origin.1), dir, 32, bounces, |_| false)
+    }
+
+    #[test]
+    fn ray_bounces_off_horizontal_wall() {
+        // 벽 행 y=3: (3,4)에서 위가 막혀 y만 뒤집힘 → (4,5) → (5,6)
+        let units = [(2, 5, 6, 1)];
+        let (mut map, index) = grid(&units);
+        walls(&mut map, &(0..10).map(|x| (x, 3)).collect::<Vec<_>>());
+        let hit = ray(&map, &index, &units, (2, 5), (1, -1), 1).unwrap();
+        assert_eq!((hit.target.index(), hit.at, hit.dir, hit.bounces_left), (2, GridPos::new(5, 6), (1, 1), 0));
+    }
+
+    #[test]
+    fn ray_bounces_off_vertical_wall() {
+        // 벽 열 x=6: (5,7)에서 오른쪽이 막혀 x만 뒤집힘 → (4,8)
+        let units = [(2, 4, 8, 1)];
+        let (mut map, index) = grid(&units);
+        walls(&mut map, &(0..10).map(|y| (6, y)).collect::<Vec<_>>());
+        let hit = ray(&map, &index, &units, (3, 5), (1, 1), 2).unwrap();
+        assert_eq!((hit.at, hit.dir, hit.bounces_left), (GridPos::new(4, 8), (-1, 1), 1));
+    }
+
+    #[test]
+    fn ray_reverses_in_corner_and_straight_into_wall() {
+        // 맵 모서리 (9,9): 두 축 다 막힘 → 되돌아와 (6,6)
+        let units = [(2, 6, 6, 1)];
+        let (map, index) = grid(&units);
+        let hit = ray(&map, &index, &units, (7, 7), (1, 1), 1).unwrap();
+        assert_eq!((hit.at, hit.dir), (GridPos::new(6, 6), (-1, -1)));
+
+        let w = world(&map, &index, &units);
+        assert_eq!(reflect(&w, GridPos::new(9, 4), (1, 0)), (-1, 0));
+    }
+
+    #[test]
+    fn ray_dies_when_bounces_run_out() {
+        // 폭 2 복도(y=4,5)에서 지그재그: (4,4)까지 튕김 3번 필요
+        let units = [(2, 4, 4, 1)];
+        let (mut map, index) = grid(&units);
+        walls(&mut map, &(0..10).flat_map(|x| [(x, 3), (x, 6)]).collect::<Vec<_>>());
+        assert_eq!(ray(&map, &index, &units, (0, 4), (1, 1), 2), None);
+        let hit = ray(&map, &index, &units, (0, 4), (1, 1), 3).unwrap();
+        assert_eq!((hit.at, hit.bounces_left), (GridPos::new(4, 4), 0));
+    }
+
+    #[test]
+    fn ricochet_branches_fan_out_left_first() {
+        assert_eq!(ricochet_dirs((1, 0), 1), [(1, 0)]);
+        assert_eq!(ricochet_dirs((1, 0), 2), [(1, 1), (1, -1)]);
+        assert_eq!(ricochet_dirs((1, 0), 3), [(1, 0), (1, 1), (1, -1)]);
+        assert_eq!(ricochet_dirs((1, 0), 9).len(), 8);
+    }
+}
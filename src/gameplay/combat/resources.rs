@@ -0,0 +1,72 @@
+// src/gameplay/combat/resources.rs
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use super::statuses::StatusConfig;
+
+/// 전투 난수원 — 시드 고정으로 재현 가능(리플레이/테스트)
+#[derive(Resource, Debug, Clone)]
+pub struct CombatRng {
+    pub seed: u64,
+    rng: StdRng,
+}
+
+impl CombatRng {
+    pub const DEFAULT_SEED: u64 = 0x5EED_C0DE;
+
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: StdRng::seed_from_u64(seed) }
+    }
+
+    /// 같은 시드로 처음부터 다시
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// [0, 1) 치명타 판정용 난수
+    #[inline]
+    pub fn roll(&mut self) -> f32 {
+        self.rng.random::<f32>()
+    }
+}
+
+impl Default for CombatRng {
+    fn default() -> Self { Self::new(Self::DEFAULT_SEED) }
+}
+
+/// 폭발/확산 등 범위 효과의 아군 피격 정책 (규칙 로드 전에는 적만)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FriendlyFire {
+    /// 적만
+    #[default]
+    EnemiesOnly,
+    /// 아군 포함, 공격자 본인 제외
+    ExceptSelf,
+    /// 공격자 본인까지 전부
+    All,
+}
+
+impl FriendlyFire {
+    /// 대상이 공격자 본인/같은 팀일 때 피해를 받는지
+    #[inline]
+    pub fn affects(self, is_self: bool, same_team: bool) -> bool {
+        match self {
+            FriendlyFire::EnemiesOnly => !same_team,
+            FriendlyFire::ExceptSelf => !is_self,
+            FriendlyFire::All => true,
+        }
+    }
+}
+
+/// 전투 규칙 설정 (rules/combat.rules.ron에서 로드)
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct CombatConfig {
+    /// 폭발 피해의 아군 피격 정책
+    pub explosion_friendly_fire: FriendlyFire,
+    /// 시너지 확산의 아군 피격 정책
+    pub spread_friendly_fire: FriendlyFire,
+    /// 곡사 최소 사거리(칸) — 이보다 가까운 칸은 곡사로 조준 불가
+    pub lobbed_min_range: u32,
+    /// 상태이상 수치
+    pub status: StatusConfig,
+}
@@ -0,0 +1,69 @@
+// src/gameplay/combat/rules.rs
+//! 전투 규칙 RON 에셋(rules/combat.rules.ron) → 런타임 CombatConfig
+use bevy::{asset::Asset, prelude::*, reflect::TypePath};
+use serde::Deserialize;
+
+use super::{
+    resources::{CombatConfig, FriendlyFire},
+    statuses::StatusConfig,
+};
+
+/// 기본 규칙 경로 (assets/ 기준)
+pub const COMBAT_RULES_PATH: &str = "rules/combat.rules.ron";
+
+/// 범위 효과 아군 피격 정책 (RON)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum FriendlyFireSpec { EnemiesOnly, ExceptSelf, All }
+
+impl From<FriendlyFireSpec> for FriendlyFire {
+    fn from(s: FriendlyFireSpec) -> Self {
+        match s {
+            FriendlyFireSpec::EnemiesOnly => FriendlyFire::EnemiesOnly,
+            FriendlyFireSpec::ExceptSelf => FriendlyFire::ExceptSelf,
+            FriendlyFireSpec::All => FriendlyFire::All,
+        }
+    }
+}
+
+/// 전투 규칙 (RON 에셋)
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct CombatRules {
+    /// 폭발 피해의 아군 피격 정책
+    pub explosion_friendly_fire: FriendlyFireSpec,
+    /// 시너지 확산의 아군 피격 정책
+    pub spread_friendly_fire: FriendlyFireSpec,
+    /// 곡사 최소 사거리(칸)
+    pub lobbed_min_range: u32,
+    /// 상태이상 수치
+    pub status: StatusConfig,
+}
+
+impl From<&CombatRules> for CombatConfig {
+    fn from(rules: &CombatRules) -> Self {
+        Self {
+            explosion_friendly_fire: rules.explosion_friendly_fire.into(),
+            spread_friendly_fire: rules.spread_friendly_fire.into(),
+            lobbed_min_range: rules.lobbed_min_range,
+            status: rules.status,
+        }
+    }
+}
+
+/// 로드 중인 규칙 핸들
+#[derive(Resource, Debug, Default)]
+pub struct CombatRulesHandle(pub Handle<CombatRules>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_rules_parse_into_config() {
+        let rules: CombatRules = ron::from_str(include_str!("../../../assets/rules/combat.rules.ron")).unwrap();
+        let config = CombatConfig::from(&rules);
+        assert_eq!(config.explosion_friendly_fire, FriendlyFire::ExceptSelf);
+        assert_eq!(config.spread_friendly_fire, FriendlyFire::EnemiesOnly);
+        assert_eq!(config.lobbed_min_range, 2);
+        assert_eq!(config.status.poison_max_stacks, 5);
+    }
+}
@@ -0,0 +1,103 @@
+// src/gameplay/combat/snapshot.rs
+//! 전투 해석용 월드 스냅샷
+//! ECS 쿼리 대신 맵/점유 인덱스/유닛 스탯 사본을 들고 순수 함수로 공격을 계산한다.
+//! 해석 중 피해/밀림은 스냅샷에만 누적되고, 결과(AttackTrace)를 시스템이 ECS에 반영.
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::components::{GridPos, TerrainKind};
+use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+use crate::gameplay::units::components::{Stats, StatusEffects, TeamId};
+
+/// 스냅샷 안의 유닛 1기
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitSnap {
+    pub pos: GridPos,
+    pub team: TeamId,
+    pub stats: Stats,
+    pub statuses: StatusEffects,
+}
+
+/// 맵 + 점유 인덱스 + 유닛 사본
+pub struct CombatWorld<'a> {
+    pub map: &'a BaseTileMap,
+    pub index: &'a GridIndex,
+    units: HashMap<Entity, UnitSnap>,
+    /// 해석 중 바뀐 점유 (GridIndex 위에 덮어씀)
+    moved: HashMap<GridPos, Option<Entity>>,
+}
+
+impl<'a> CombatWorld<'a> {
+    pub fn new(map: &'a BaseTileMap, index: &'a GridIndex) -> Self {
+        Self { map, index, units: HashMap::default(), moved: HashMap::default() }
+    }
+
+    pub fn insert(&mut self, entity: Entity, unit: UnitSnap) {
+        self.units.insert(entity, unit);
+    }
+
+    #[inline]
+    pub fn unit(&self, entity: Entity) -> Option<&UnitSnap> {
+        self.units.get(&entity)
+    }
+
+    #[inline]
+    pub fn unit_mut(&mut self, entity: Entity) -> Option<&mut UnitSnap> {
+        self.units.get_mut(&entity)
+    }
+
+    /// 살아 있는 유닛 전체 (순서 무관 — 선택 시 호출 측에서 정렬 키로 결정)
+    pub fn alive_units(&self) -> impl Iterator<Item = (Entity, &UnitSnap)> {
+        self.units.iter().filter(|(_, u)| !u.stats.is_dead()).map(|(e, u)| (*e, u))
+    }
+
+    /// p 칸 점유자 (이번 해석에서 죽었어도 아직 칸을 차지함)
+    pub fn occupant(&self, p: GridPos) -> Option<Entity> {
+        match self.moved.get(&p) {
+            Some(over) => *over,
+            None => self.index.get_pos(p),
+        }
+    }
+
+    /// p 칸의 살아 있는 유닛 (이번 해석에서 이미 죽은 유닛은 제외)
+    pub fn unit_at(&self, p: GridPos) -> Option<Entity> {
+        let e = self.occupant(p)?;
+        self.units.get(&e).filter(|u| !u.stats.is_dead()).map(|_| e)
+    }
+
+    /// 유닛을 to로 옮김(스냅샷만). to가 비어 있는지는 호출 측 책임
+    pub fn move_unit(&mut self, entity: Entity, to: GridPos) {
+        let Some(unit) = self.units.get_mut(&entity) else { return; };
+        let from = unit.pos;
+        unit.pos = to;
+        self.moved.insert(from, None);
+        self.moved.insert(to, Some(entity));
+    }
+
+    #[inline]
+    pub fn in_bounds(&self, p: GridPos) -> bool {
+        self.map.in_bounds_pos(p)
+    }
+
+    /// 투사체를 막는 칸 (맵 밖 포함)
+    #[inline]
+    pub fn is_wall(&self, p: GridPos) -> bool {
+        !self.in_bounds(p) || self.map.terrain_at_pos(p) == TerrainKind::Wall
+    }
+
+    /// 밀려나는 유닛이 들어갈 수 없는 지형 (벽/물/맵 밖)
+    #[inline]
+    pub fn blocks_push(&self, p: GridPos) -> bool {
+        !self.in_bounds(p) || matches!(self.map.terrain_at_pos(p), TerrainKind::Wall | TerrainKind::Water)
+    }
+
+    /// p에서 dir로 한 칸 (맵 밖이면 None)
+    pub fn step(&self, p: GridPos, dir: (i32, i32)) -> Option<GridPos> {
+        let x = p.x as i32 + dir.0;
+        let y = p.y as i32 + dir.1;
+        if x < 0 || y < 0 { return None; }
+        let q = GridPos::new(x as u32, y as u32);
+        self.in_bounds(q).then_some(q)
+    }
+}
@@ -0,0 +1,209 @@
+// src/gameplay/combat/statuses.rs
+//! ② Element → 상태이상(화염/빙결/전기/독): 부여/중첩/턴 경과 규칙
+//! 컴포넌트(StatusEffects)는 units::components, 여기서는 순수 규칙만.
+//!
+//! 중첩 규칙
+//! - 화염: 지속시간만 갱신(더 긴 쪽), 피해량은 중첩되지 않음
+//! - 빙결: 둔화 상태에서 한 번 더 맞으면 빙결(이동 불가)로 승격, 지속시간 갱신
+//! - 전기: 지속시간만 갱신(더 긴 쪽)
+//! - 독: 스택 +1(최대 poison_max_stacks), 지속시간 갱신
+//!
+//! 예산 제한(clamp_budgets)
+//! - 빙결(둔화): 그 페이즈 남은 이동력을 per_turn - ice_move_penalty로 상한 (per_turn 자체는 그대로)
+//! - 빙결(승격 후): 남은 이동력 0
+//! - 전기: 남은 AP 0
+use serde::Deserialize;
+
+use crate::gameplay::units::components::{
+    ActionBudget, Burning, Chill, Element, MoveBudget, Poison, StatusEffects, Stun,
+};
+
+/// 상태이상 수치 (rules/combat.rules.ron의 status, 로드 전에는 전부 0)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+pub struct StatusConfig {
+    /// 화염: 페이즈당 피해 / 지속 페이즈
+    pub fire_damage: i32,
+    pub fire_turns: u32,
+    /// 빙결(둔화): 남은 이동력 상한을 per_turn에서 깎는 양 / 지속 페이즈
+    pub ice_move_penalty: u32,
+    pub ice_turns: u32,
+    /// 빙결(승격 후): 지속 페이즈
+    pub freeze_turns: u32,
+    /// 전기: 행동 불가 페이즈
+    pub stun_turns: u32,
+    /// 독: 스택당 페이즈 피해 / 지속 페이즈 / 최대 스택
+    pub poison_damage_per_stack: i32,
+    pub poison_turns: u32,
+    pub poison_max_stacks: u32,
+}
+
+/// 속성 1회 부여 (중첩 규칙은 모듈 주석)
+pub fn apply_element(s: &mut StatusEffects, element: Element, cfg: &StatusConfig) {
+    match element {
+        Element::Fire => {
+            let turns = s.burning.map_or(0, |b| b.turns).max(cfg.fire_turns);
+            s.burning = Some(Burning { damage: cfg.fire_damage, turns });
+        }
+        Element::Ice => {
+            s.chill = Some(match s.chill {
+                None => Chill { frozen: false, turns: cfg.ice_turns },
+                Some(_) => Chill { frozen: true, turns: cfg.freeze_turns },
+            });
+        }
+        Element::Electric => {
+            let turns = s.stun.map_or(0, |st| st.turns).max(cfg.stun_turns);
+            s.stun = Some(Stun { turns });
+        }
+        Element::Poison => {
+            let stacks = s.poison.map_or(0, |p| p.stacks).saturating_add(1).min(cfg.poison_max_stacks.max(1));
+            s.poison = Some(Poison { stacks, turns: cfg.poison_turns });
+        }
+    }
+}
+
+/// 이 속성의 상태이상이 걸려 있는지
+pub fn has_element(s: &StatusEffects, element: Element) -> bool {
+    match element {
+        Element::Fire => s.burning.is_some(),
+        Element::Ice => s.chill.is_some(),
+        Element::Electric => s.stun.is_some(),
+        Element::Poison => s.poison.is_some(),
+    }
+}
+
+/// 이 속성의 상태이상 해제
+pub fn clear_element(s: &mut StatusEffects, element: Element) {
+    match element {
+        Element::Fire => s.burning = None,
+        Element::Ice => s.chill = None,
+        Element::Electric => s.stun = None,
+        Element::Poison => s.poison = None,
+    }
+}
+
+/// 걸려 있는 속성 목록(고정 순서: 화염 → 빙결 → 전기 → 독)
+pub fn active_elements(s: &StatusEffects) -> impl Iterator<Item = Element> + '_ {
+    [Element::Fire, Element::Ice, Element::Electric, Element::Poison]
+        .into_iter()
+        .filter(|&el| has_element(s, el))
+}
+
+/// 페이즈 시작 지속 피해(화염 + 독 스택)
+pub fn tick_damage(s: &StatusEffects, cfg: &StatusConfig) -> i32 {
+    let fire = s.burning.map_or(0, |b| b.damage);
+    let poison = s.poison.map_or(0, |p| p.stacks as i32 * cfg.poison_damage_per_stack);
+    fire + poison
+}
+
+/// 예산 제한: 빙결(둔화/이동 불가), 전기(행동 불가) — 리셋 직후와 부여 직후에 적용
+/// - 남은 예산(current)만 깎음, per_turn은 그대로 (상태이상이 풀리면 다음 리셋부터 원래대로)
+pub fn clamp_budgets(s: &StatusEffects, mv: &mut MoveBudget, ap: &mut ActionBudget, cfg: &StatusConfig) {
+    if let Some(chill) = s.chill {
+        let cap = if chill.frozen { 0 } else { mv.per_turn.saturating_sub(cfg.ice_move_penalty) };
+        mv.current = mv.current.min(cap);
+    }
+    if s.stun.is_some() {
+        ap.current = 0;
+    }
+}
+
+/// 페이즈 1회 경과: 남은 페이즈 감소, 0이 된 상태이상 해제
+pub fn advance(s: &mut StatusEffects) {
+    fn dec(turns: &mut u32) -> bool {
+        *turns = turns.saturating_sub(1);
+        *turns > 0
+    }
+    s.burning = s.burning.and_then(|mut b| dec(&mut b.turns).then_some(b));
+    s.chill = s.chill.and_then(|mut c| dec(&mut c.turns).then_some(c));
+    s.stun = s.stun.and_then(|mut st| dec(&mut st.turns).then_some(st));
+    s.poison = s.poison.and_then(|mut p| dec(&mut p.turns).then_some(p));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cfg() -> StatusConfig {
+        StatusConfig {
+            fire_damage: 2,
+            fire_turns: 2,
+            ice_move_penalty: 2,
+            ice_turns: 2,
+            freeze_turns: 1,
+            stun_turns: 1,
+            poison_damage_per_stack: 1,
+            poison_turns: 3,
+            poison_max_stacks: 3,
+        }
+    }
+
+    fn applied(elements: &[Element]) -> StatusEffects {
+        let mut s = StatusEffects::default();
+        for &el in elements {
+            apply_element(&mut s, el, &cfg());
+        }
+        s
+    }
+
+    #[test]
+    fn fire_deals_flat_damage_each_phase_without_stacking() {
+        let s = applied(&[Element::Fire, Element::Fire]);
+        assert_eq!(s.burning, Some(Burning { damage: 2, turns: 2 }));
+        assert_eq!(tick_damage(&s, &cfg()), 2);
+    }
+
+    #[test]
+    fn poison_stacks_up_to_max_and_scales_damage() {
+        let s = applied(&[Element::Poison, Element::Poison]);
+        assert_eq!(s.poison, Some(Poison { stacks: 2, turns: 3 }));
+        assert_eq!(tick_damage(&s, &cfg()), 2);
+
+        let s = applied(&[Element::Poison; 5]);
+        assert_eq!(s.poison.map(|p| p.stacks), Some(3));
+        // 화염 + 독은 합산
+        let mut s = s;
+        apply_element(&mut s, Element::Fire, &cfg());
+        assert_eq!(tick_damage(&s, &cfg()), 2 + 3);
+    }
+
+    #[test]
+    fn electric_stun_zeroes_action_points() {
+        let s = applied(&[Element::Electric]);
+        assert_eq!(s.stun, Some(Stun { turns: 1 }));
+        let (mut mv, mut ap) = (MoveBudget::new(4), ActionBudget::new(2));
+        clamp_budgets(&s, &mut mv, &mut ap, &cfg());
+        assert_eq!((mv.current, ap.current), (4, 0));
+    }
+
+    #[test]
+    fn ice_caps_remaining_move_and_freeze_stops_it_per_turn_untouched() {
+        let (mut mv, mut ap) = (MoveBudget::new(5), ActionBudget::new(2));
+        clamp_budgets(&applied(&[Element::Ice]), &mut mv, &mut ap, &cfg());
+        assert_eq!((mv.current, mv.per_turn, ap.current), (3, 5, 2));
+
+        // 이미 상한보다 적게 남았으면 그대로
+        mv.current = 1;
+        clamp_budgets(&applied(&[Element::Ice]), &mut mv, &mut ap, &cfg());
+        assert_eq!(mv.current, 1);
+
+        let frozen = applied(&[Element::Ice, Element::Ice]);
+        assert_eq!(frozen.chill, Some(Chill { frozen: true, turns: 1 }));
+        mv.reset();
+        clamp_budgets(&frozen, &mut mv, &mut ap, &cfg());
+        assert_eq!((mv.current, mv.per_turn), (0, 5));
+    }
+
+    #[test]
+    fn durations_tick_down_and_expire() {
+        let mut s = applied(&[Element::Fire, Element::Electric, Element::Poison]);
+        advance(&mut s);
+        assert_eq!(s.burning.map(|b| b.turns), Some(1));
+        assert_eq!(s.stun, None);
+        assert_eq!(s.poison.map(|p| p.turns), Some(2));
+        advance(&mut s);
+        assert_eq!(s.burning, None);
+        assert_eq!(s.poison.map(|p| (p.stacks, p.turns)), Some((1, 1)));
+        advance(&mut s);
+        assert_eq!(s, StatusEffects::default());
+    }
+}
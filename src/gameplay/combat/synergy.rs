@@ -0,0 +1,199 @@
+// src/gameplay/combat/synergy.rs
+//! ② 속성 시너지: 이미 상태이상이 걸린 유닛에 다른 속성이 착탄하면 반응
+//! 반응표는 RON 에셋(rules/elemental.synergy.ron) — (기존 속성, 새 속성) → 반응 1개
+//!
+//! 규칙
+//! - 같은 속성 재착탄은 반응 없음(statuses의 중첩 규칙만)
+//! - 기존 속성이 여럿이면 고정 순서(화염 → 빙결 → 전기 → 독)로 각각 반응
+//! - consume 반응: 기존 상태이상 해제 + 새 속성은 부여하지 않음(둘 다 소모)
+//! - 확산으로 번진 속성은 다시 반응하지 않음(연쇄 반응 없음)
+use std::collections::HashMap;
+
+use bevy::{asset::Asset, prelude::*, reflect::TypePath};
+use serde::Deserialize;
+
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::units::{components::Element, spec::ElementSpec};
+
+use super::{resources::FriendlyFire, snapshot::CombatWorld};
+
+/// 기본 반응표 경로 (assets/ 기준)
+pub const SYNERGY_TABLE_PATH: &str = "rules/elemental.synergy.ron";
+
+/// 반응 효과 (RON)
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum SynergyEffectSpec {
+    Damage { amount: i32 },
+    Spread { element: ElementSpec, radius: u8 },
+    Stun { turns: u32 },
+}
+
+/// 반응 1개 (RON)
+#[derive(Debug, Clone, Deserialize)]
+pub struct SynergySpec {
+    pub name: String,
+    pub effect: SynergyEffectSpec,
+    #[serde(default)]
+    pub consume: bool,
+}
+
+/// 기존 속성 → 새 속성 → 반응 (RON 에셋)
+/// - 표에 없는 조합은 반응 없음
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct SynergyTable {
+    pub reactions: HashMap<ElementSpec, HashMap<ElementSpec, SynergySpec>>,
+}
+
+/// 반응 효과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SynergyEffect {
+    /// 대상에게 추가 피해(치명타 없음)
+    Damage { amount: i32 },
+    /// 대상 주변(체비셰프 radius) 유닛에 element 부여(피해 없음, 아군 포함 여부는 규칙)
+    Spread { element: Element, radius: u8 },
+    /// 대상 행동 불가 turns 페이즈(기존 기절보다 짧아지지 않음)
+    Stun { turns: u32 },
+}
+
+impl From<SynergyEffectSpec> for SynergyEffect {
+    fn from(s: SynergyEffectSpec) -> Self {
+        match s {
+            SynergyEffectSpec::Damage { amount } => Self::Damage { amount },
+            SynergyEffectSpec::Spread { element, radius } => Self::Spread { element: element.into(), radius },
+            SynergyEffectSpec::Stun { turns } => Self::Stun { turns },
+        }
+    }
+}
+
+/// 반응 1개 (런타임)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Synergy {
+    /// HUD 표시 이름
+    pub name: String,
+    pub effect: SynergyEffect,
+    /// 두 속성을 모두 소모하는지
+    pub consume: bool,
+}
+
+/// 런타임 반응표: 로드된 SynergyTable을 펼친 것 (로드 전에는 반응 없음)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SynergyMatrix {
+    reactions: HashMap<(Element, Element), Synergy>,
+}
+
+impl SynergyMatrix {
+    /// existing이 걸린 유닛에 incoming이 착탄했을 때의 반응
+    #[inline]
+    pub fn get(&self, existing: Element, incoming: Element) -> Option<&Synergy> {
+        if existing == incoming { return None; }
+        self.reactions.get(&(existing, incoming))
+    }
+}
+
+impl From<&SynergyTable> for SynergyMatrix {
+    fn from(table: &SynergyTable) -> Self {
+        let reactions = table
+            .reactions
+            .iter()
+            .flat_map(|(&existing, row)| {
+                row.iter().map(move |(&incoming, spec)| {
+                    let synergy = Synergy { name: spec.name.clone(), effect: spec.effect.into(), consume: spec.consume };
+                    ((existing.into(), incoming.into()), synergy)
+                })
+            })
+            .collect();
+        Self { reactions }
+    }
+}
+
+/// 로드 중인 반응표 핸들
+#[derive(Resource, Debug, Default)]
+pub struct SynergyTableHandle(pub Handle<SynergyTable>);
+
+/// 확산 대상: center 기준 체비셰프 radius 이내의 살아 있는 유닛(반응 대상 source 제외)
+/// - 아군 포함 여부는 공격자 기준 FriendlyFire 정책
+/// - 순서: y → x
+pub fn spread_targets(
+    world: &CombatWorld,
+    center: GridPos,
+    radius: u8,
+    source: Entity,
+    attacker: Entity,
+    ff: FriendlyFire,
+) -> Vec<(Entity, GridPos)> {
+    let Some(team) = world.unit(attacker).map(|u| u.team) else { return Vec::new(); };
+    let mut out: Vec<(Entity, GridPos)> = world
+        .alive_units()
+        .filter(|&(e, _)| e != source)
+        .filter(|&(e, u)| ff.affects(e == attacker, u.team == team))
+        .filter(|(_, u)| center.chebyshev(u.pos) <= radius as u32)
+        .map(|(e, u)| (e, u.pos))
+        .collect();
+    out.sort_by_key(|&(_, p)| (p.y, p.x));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::combat::snapshot::UnitSnap;
+    use crate::gameplay::tiles::components::TerrainKind;
+    use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+    use crate::gameplay::units::components::{Stats, TeamId};
+
+    fn shipped() -> SynergyMatrix {
+        let table = ron::from_str::<SynergyTable>(include_str!("../../../assets/rules/elemental.synergy.ron")).unwrap();
+        SynergyMatrix::from(&table)
+    }
+
+    #[test]
+    fn shipped_table_maps_pairs_to_reactions() {
+        let m = shipped();
+        let melt = m.get(Element::Fire, Element::Ice).unwrap();
+        assert_eq!(melt.name, "Melt");
+        assert_eq!((melt.effect, melt.consume), (SynergyEffect::Damage { amount: 4 }, true));
+        assert_eq!(m.get(Element::Ice, Element::Electric).map(|s| s.effect), Some(SynergyEffect::Stun { turns: 2 }));
+        assert_eq!(
+            m.get(Element::Poison, Element::Fire).map(|s| (s.effect, s.consume)),
+            Some((SynergyEffect::Spread { element: Element::Fire, radius: 1 }, false))
+        );
+    }
+
+    #[test]
+    fn same_element_and_unlisted_pairs_have_no_reaction() {
+        let m = shipped();
+        assert!(m.get(Element::Fire, Element::Fire).is_none());
+        assert!(m.get(Element::Fire, Element::Electric).is_none());
+        // 로드 전 기본값은 반응 없음
+        assert!(SynergyMatrix::default().get(Element::Fire, Element::Ice).is_none());
+    }
+
+    #[test]
+    fn spread_targets_stay_in_radius_and_skip_source() {
+        // 1 공격자(팀0), 2 반응 대상(팀1), 3·4 반경 안(팀1), 5 반경 밖(팀1), 6 반경 안 아군(팀0)
+        let units = [(1, 0, 0, 0), (2, 5, 5, 1), (3, 6, 6, 1), (4, 4, 5, 1), (5, 7, 5, 1), (6, 5, 4, 0)];
+        let map = BaseTileMap::filled(10, 10, TerrainKind::Ground);
+        let mut index = GridIndex::new(10, 10);
+        for &(id, x, y, _) in &units {
+            index.place(x, y, Entity::from_raw(id)).unwrap();
+        }
+        let mut w = CombatWorld::new(&map, &index);
+        for &(id, x, y, team) in &units {
+            let snap = UnitSnap {
+                pos: GridPos::new(x, y),
+                team: TeamId(team),
+                stats: Stats::new(10, 0),
+                statuses: Default::default(),
+            };
+            w.insert(Entity::from_raw(id), snap);
+        }
+        let ids = |ff| -> Vec<u32> {
+            spread_targets(&w, GridPos::new(5, 5), 1, Entity::from_raw(2), Entity::from_raw(1), ff)
+                .iter()
+                .map(|(e, _)| e.index())
+                .collect()
+        };
+        assert_eq!(ids(FriendlyFire::EnemiesOnly), vec![4, 3]);
+        assert_eq!(ids(FriendlyFire::ExceptSelf), vec![6, 4, 3]);
+    }
+}
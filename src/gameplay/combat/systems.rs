@@ -0,0 +1,206 @@
+// src/gameplay/combat/systems.rs
+use bevy::prelude::*;
+
+use crate::gameplay::commands::events::AttackResolved;
+use crate::gameplay::turns::events::TurnStarted;
+use crate::gameplay::units::components::{
+    ActionBudget, Attack, ElementalImbue, FiringMods, MoveBudget, OnHitEffects, Propagation, Stats, StatusEffects,
+    TeamId, Unit,
+};
+
+use super::{
+    attack::apply_damage,
+    context::CombatCtx,
+    events::{AttackApplied, DamageApplied, ElementApplied, KnockbackApplied, SynergyTriggered},
+    pipeline::{DisplacementKind, Shot},
+    resources::CombatConfig,
+    rules::{COMBAT_RULES_PATH, CombatRules, CombatRulesHandle},
+    statuses::{advance, clamp_budgets, has_element, tick_damage},
+    synergy::{SYNERGY_TABLE_PATH, SynergyEffect, SynergyMatrix, SynergyTable, SynergyTableHandle},
+};
+
+/// 공격자에서 Shot을 만드는 데 필요한 컴포넌트
+type ShotSource = (
+    &'static Attack,
+    Option<&'static Propagation>,
+    Option<&'static OnHitEffects>,
+    Option<&'static FiringMods>,
+    Option<&'static mut ElementalImbue>,
+);
+
+/// AttackResolved → 공격 파이프라인(발사 + 직접 명중 + OnHit + 전파) → Stats 반영
+/// - 히트마다 DamageApplied, 넉백마다 KnockbackApplied, 공격 1회당 AttackApplied(trace) 발행
+/// - 속성이 한 번이라도 부여되면 ElementalImbue 쿨다운 소모(cooldown = cooldown_max)
+/// - 스냅샷은 공격마다 새로(앞선 공격의 피해가 반영된 상태)
+pub fn apply_attack_damage(
+    mut ev_attack: EventReader<AttackResolved>,
+    mut ctx: CombatCtx,
+    mut q_attacker: Query<ShotSource, With<Unit>>,
+    mut ev_damage: EventWriter<DamageApplied>,
+    mut ev_knockback: EventWriter<KnockbackApplied>,
+    mut ev_applied: EventWriter<AttackApplied>,
+) {
+    for e in ev_attack.read() {
+        let Ok((attack, propagation, on_hit, firing, mut imbue)) = q_attacker.get_mut(e.attacker) else { continue; };
+        let element = imbue.as_deref().and_then(|i| i.element.filter(|_| i.cooldown == 0));
+        let shot = Shot {
+            attacker: e.attacker,
+            from: e.from,
+            at: e.at,
+            dir: e.dir,
+            attack: *attack,
+            propagation: propagation.copied().unwrap_or_default(),
+            on_hit: on_hit.copied().unwrap_or_default(),
+            firing: firing.copied().unwrap_or_default(),
+            element,
+        };
+
+        let Some(trace) = ctx.resolve(&shot, e.projectiles.clone()) else { continue; };
+        if let Some(imbue) = imbue.as_mut().filter(|_| trace.element_triggered()) {
+            imbue.cooldown = imbue.cooldown_max;
+        }
+        for hit in &trace.hits {
+            ev_damage.write(DamageApplied { source: Some(e.attacker), target: hit.target, breakdown: hit.breakdown });
+        }
+        for d in trace.displacements.iter().filter(|d| d.kind == DisplacementKind::Knockback) {
+            ev_knockback.write(KnockbackApplied {
+                attacker: e.attacker,
+                target: d.target,
+                from: d.from,
+                path: d.path.clone(),
+                collided: d.collided,
+            });
+        }
+        ev_applied.write(AttackApplied { trace });
+    }
+}
+
+/// AttackApplied(trace) → 속성/시너지 결과 이벤트
+/// - 속성 착탄(반응으로 소모된 것 제외)과 확산마다 ElementApplied
+/// - 반응마다 SynergyTriggered (HUD/VFX)
+pub fn publish_element_events(
+    mut ev_applied: EventReader<AttackApplied>,
+    q_statuses: Query<&StatusEffects>,
+    mut ev_element: EventWriter<ElementApplied>,
+    mut ev_synergy: EventWriter<SynergyTriggered>,
+) {
+    for AttackApplied { trace } in ev_applied.read() {
+        let source = trace.attacker;
+        let mut write = |target: Entity, element| {
+            let Ok(statuses) = q_statuses.get(target) else { return; };
+            if has_element(statuses, element) {
+                ev_element.write(ElementApplied { source, target, element, statuses: *statuses });
+            }
+        };
+        for hit in &trace.hits {
+            if let Some(element) = hit.element {
+                write(hit.target, element);
+            }
+        }
+        for s in &trace.synergies {
+            if let SynergyEffect::Spread { element, .. } = s.synergy.effect {
+                s.spread.iter().for_each(|&e| write(e, element));
+            }
+            ev_synergy.write(SynergyTriggered {
+                attacker: source,
+                target: s.target,
+                at: s.at,
+                existing: s.existing,
+                incoming: s.incoming,
+                name: s.synergy.name.clone(),
+                spread: s.spread.clone(),
+            });
+        }
+    }
+}
+
+/// 상태이상 틱 대상 유닛
+type StatusUnit = (
+    Entity,
+    &'static TeamId,
+    &'static mut Stats,
+    &'static mut StatusEffects,
+    &'static mut MoveBudget,
+    &'static mut ActionBudget,
+);
+
+/// TurnStarted → 그 팀 유닛의 상태이상 1페이즈 진행
+/// - 지속 피해(화염 + 독) → DamageApplied(source 없음), 사망 처리는 lifecycle
+/// - 리셋된 예산에 빙결/전기 제한 적용 후 남은 페이즈 1 감소
+pub fn tick_status_effects(
+    mut ev_started: EventReader<TurnStarted>,
+    config: Res<CombatConfig>,
+    mut q_units: Query<StatusUnit, With<Unit>>,
+    mut ev_damage: EventWriter<DamageApplied>,
+) {
+    let cfg = &config.status;
+    for started in ev_started.read() {
+        for (entity, &team, mut stats, mut statuses, mut mv, mut ap) in &mut q_units {
+            if team != started.team || *statuses == StatusEffects::default() { continue; }
+
+            let dot = tick_damage(&statuses, cfg);
+            if dot > 0 {
+                let breakdown = apply_damage(*stats, dot, dot, false);
+                stats.set_if_neq(breakdown.after);
+                ev_damage.write(DamageApplied { source: None, target: entity, breakdown });
+            }
+            clamp_budgets(&statuses, &mut mv, &mut ap, cfg);
+            advance(&mut statuses);
+        }
+    }
+}
+
+/// 상태이상이 바뀐 유닛의 예산
+type StatusBudgets = (&'static StatusEffects, &'static mut MoveBudget, &'static mut ActionBudget);
+
+/// 공격으로 상태이상이 바뀐 유닛 → 남은 예산에 즉시 제한(빙결/전기)
+pub fn enforce_status_budgets(
+    config: Res<CombatConfig>,
+    mut q_units: Query<StatusBudgets, (With<Unit>, Changed<StatusEffects>)>,
+) {
+    for (statuses, mut mv, mut ap) in &mut q_units {
+        clamp_budgets(statuses, &mut mv, &mut ap, &config.status);
+    }
+}
+
+/// 시너지 반응표 로드 요청
+pub fn load_synergy_table(asset_server: Res<AssetServer>, mut handle: ResMut<SynergyTableHandle>) {
+    handle.0 = asset_server.load(SYNERGY_TABLE_PATH);
+}
+
+/// 반응표 로드/핫리로드 시 런타임 반응표 갱신
+pub fn apply_synergy_table(
+    mut ev: EventReader<AssetEvent<SynergyTable>>,
+    handle: Res<SynergyTableHandle>,
+    tables: Res<Assets<SynergyTable>>,
+    mut matrix: ResMut<SynergyMatrix>,
+) {
+    for e in ev.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = e else { continue; };
+        if *id != handle.0.id() { continue; }
+        if let Some(table) = tables.get(*id) {
+            *matrix = SynergyMatrix::from(table);
+        }
+    }
+}
+
+/// 전투 규칙 로드 요청
+pub fn load_combat_rules(asset_server: Res<AssetServer>, mut handle: ResMut<CombatRulesHandle>) {
+    handle.0 = asset_server.load(COMBAT_RULES_PATH);
+}
+
+/// 규칙 로드/핫리로드 시 CombatConfig 갱신
+pub fn apply_combat_rules(
+    mut ev: EventReader<AssetEvent<CombatRules>>,
+    handle: Res<CombatRulesHandle>,
+    rules: Res<Assets<CombatRules>>,
+    mut config: ResMut<CombatConfig>,
+) {
+    for e in ev.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = e else { continue; };
+        if *id != handle.0.id() { continue; }
+        if let Some(rules) = rules.get(*id) {
+            *config = CombatConfig::from(rules);
+        }
+    }
+}
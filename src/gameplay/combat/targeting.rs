@@ -0,0 +1,105 @@
+// src/gameplay/combat/targeting.rs
+//! 사선(line of fire) 판정 — 공격 실행기/조준 미리보기/파이프라인 공용
+//! - 직사: 사수와 착탄 칸 사이의 첫 벽 또는 점유 칸에서 멈춤
+//! - 곡사(lobbed): 중간은 무시, 착탄 칸만 검사. 대신 최소 사거리 있음(CombatConfig::lobbed_min_range)
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::components::{GridPos, TerrainKind};
+use crate::gameplay::tiles::resources::BaseTileMap;
+
+/// 사선 판정 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineOfFire {
+    /// 착탄 칸까지 막힘 없음
+    Clear,
+    /// 벽(또는 벽인 착탄 칸)에 막힘
+    Wall { at: GridPos },
+    /// 착탄 칸 앞의 유닛에 막힘(직사만)
+    Unit { at: GridPos, unit: Entity },
+}
+
+impl LineOfFire {
+    #[inline] pub fn is_clear(self) -> bool { matches!(self, LineOfFire::Clear) }
+
+    /// 투사체가 실제로 멈추는 칸 (Clear면 착탄 칸)
+    #[inline]
+    pub fn impact(self, landing: GridPos) -> GridPos {
+        match self {
+            LineOfFire::Clear => landing,
+            LineOfFire::Wall { at } | LineOfFire::Unit { at, .. } => at,
+        }
+    }
+}
+
+/// from → to 사선 판정 (from/to는 직선 또는 대각 위라고 가정)
+/// - `occupant`: 칸 점유 유닛 조회(실행기는 GridIndex, 파이프라인은 스냅샷)
+/// - from 칸 자체와 to 칸의 점유는 검사하지 않음(사수/대상)
+pub fn line_of_fire(
+    map: &BaseTileMap,
+    occupant: impl Fn(GridPos) -> Option<Entity>,
+    from: GridPos,
+    to: GridPos,
+    lobbed: bool,
+) -> LineOfFire {
+    let is_wall = |p: GridPos| !map.in_bounds_pos(p) || map.terrain_at_pos(p) == TerrainKind::Wall;
+
+    if !lobbed {
+        let dir = ((to.x as i32 - from.x as i32).signum(), (to.y as i32 - from.y as i32).signum());
+        let mut p = from;
+        loop {
+            let x = p.x as i32 + dir.0;
+            let y = p.y as i32 + dir.1;
+            if x < 0 || y < 0 { return LineOfFire::Wall { at: p }; }
+            p = GridPos::new(x as u32, y as u32);
+            if p == to { break; }
+            if is_wall(p) { return LineOfFire::Wall { at: p }; }
+            if let Some(unit) = occupant(p) { return LineOfFire::Unit { at: p, unit }; }
+        }
+    }
+
+    if is_wall(to) { LineOfFire::Wall { at: to } } else { LineOfFire::Clear }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10x10 평지에 벽 칸 찍기
+    fn map_with_walls(cells: &[(u32, u32)]) -> BaseTileMap {
+        let mut map = BaseTileMap::filled(10, 10, TerrainKind::Ground);
+        for &(x, y) in cells {
+            let i = map.idx(x, y);
+            map.terrain[i] = TerrainKind::Wall;
+        }
+        map
+    }
+
+    #[test]
+    fn wall_blocks_direct_fire_but_not_lobbed() {
+        let map = map_with_walls(&[(4, 5)]);
+        let (from, to) = (GridPos::new(2, 5), GridPos::new(6, 5));
+        let direct = line_of_fire(&map, |_| None, from, to, false);
+        assert_eq!(direct, LineOfFire::Wall { at: GridPos::new(4, 5) });
+        assert_eq!(direct.impact(to), GridPos::new(4, 5));
+        assert_eq!(line_of_fire(&map, |_| None, from, to, true), LineOfFire::Clear);
+    }
+
+    #[test]
+    fn unit_in_between_blocks_direct_fire_only() {
+        let map = map_with_walls(&[]);
+        let blocker = Entity::from_raw(7);
+        let occupant = |p: GridPos| (p == GridPos::new(4, 4)).then_some(blocker);
+        let (from, to) = (GridPos::new(2, 2), GridPos::new(6, 6));
+        let direct = line_of_fire(&map, occupant, from, to, false);
+        assert_eq!(direct, LineOfFire::Unit { at: GridPos::new(4, 4), unit: blocker });
+        assert_eq!(line_of_fire(&map, occupant, from, to, true), LineOfFire::Clear);
+    }
+
+    #[test]
+    fn wall_landing_blocks_both_kinds() {
+        let map = map_with_walls(&[(6, 5)]);
+        let (from, to) = (GridPos::new(2, 5), GridPos::new(6, 5));
+        assert_eq!(line_of_fire(&map, |_| None, from, to, false), LineOfFire::Wall { at: to });
+        assert_eq!(line_of_fire(&map, |_| None, from, to, true), LineOfFire::Wall { at: to });
+    }
+}
@@ -0,0 +1,138 @@
+// src/gameplay/commands/events.rs
+use bevy::prelude::*;
+use crate::gameplay::combat::Projectile;
+use crate::gameplay::tiles::components::GridPos;
+
+/// 이동 실행 결과(성공)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct MoveApplied {
+    pub entity: Entity,
+    pub from: GridPos,
+    pub to: GridPos,
+    /// 실제 지나간 경로 (출발점 제외, 도착점 포함)
+    pub path: Vec<GridPos>,
+    /// 소모한 이동 예산
+    pub cost: u32,
+}
+
+/// 이동 실행 결과(거부)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveRejected {
+    /// 선택 유닛이 없으면 None
+    pub entity: Option<Entity>,
+    pub to: GridPos,
+    pub reason: MoveRejectReason,
+}
+
+/// 이동 거부 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveRejectReason {
+    /// 선택된 유닛이 없음(또는 유닛이 아님)
+    NoUnitSelected,
+    /// 활성 팀 페이즈가 아닌 유닛
+    NotActiveTeam,
+    /// 타일맵/그리드 인덱스가 아직 없음
+    MapNotReady,
+    /// 맵 밖 좌표
+    OutOfBounds,
+    /// 제자리 이동
+    SameCell,
+    /// 이번 페이즈에 이미 공격함 (ActionTraits::can_move_after_attack 없음)
+    AlreadyAttacked,
+    /// 도착 칸이 지형/점유로 막혀 있음
+    Blocked,
+    /// 도착 칸은 비었지만 이어지는 경로가 없음
+    NoPath,
+    /// 경로 비용이 남은 이동 예산보다 큼
+    OutOfBudget { needed: u32, available: u32 },
+}
+
+/// 공격 실행 결과(성공)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AttackResolved {
+    pub attacker: Entity,
+    pub from: GridPos,
+    /// 조준한 타일
+    pub at: GridPos,
+    /// 조준 타일의 대상 유닛
+    pub target: Entity,
+    /// 단위 조준 방향 (dx, dy ∈ -1..=1)
+    pub dir: (i32, i32),
+    /// 조준 거리(칸)
+    pub distance: u32,
+    /// 소모한 AP
+    pub ap_spent: u32,
+    /// 발사할 투사체(발사 순서, 탄창 제한 반영)
+    pub projectiles: Vec<Projectile>,
+}
+
+/// 공격 실행 결과(거부)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttackRejected {
+    /// 선택 유닛이 없으면 None
+    pub attacker: Option<Entity>,
+    pub at: GridPos,
+    pub reason: AttackRejectReason,
+}
+
+/// 공격 거부 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackRejectReason {
+    /// 선택된 유닛이 없음(또는 공격 가능한 유닛이 아님)
+    NoUnitSelected,
+    /// 활성 팀 페이즈가 아닌 유닛
+    NotActiveTeam,
+    /// 타일맵/그리드 인덱스가 아직 없음
+    MapNotReady,
+    /// 맵 밖 좌표
+    OutOfBounds,
+    /// 자기 칸 조준
+    SameCell,
+    /// AimDirs 기준 직선/대각 조준선 위가 아님
+    NotInLine,
+    /// 사거리 밖
+    OutOfRange { distance: u32, range: u32 },
+    /// 곡사 최소 사거리보다 가까움
+    TooClose { distance: u32, min: u32 },
+    /// 사선이 벽/유닛에 막힘(직사) 또는 착탄 칸이 벽(곡사)
+    LineBlocked { at: GridPos },
+    /// 조준 타일에 유닛 없음
+    NoTarget,
+    /// 탄창이 빔
+    OutOfAmmo,
+    /// AP 부족
+    OutOfActions { needed: u32, available: u32 },
+}
+
+/// 재장전 실행 결과(성공)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReloadApplied {
+    pub entity: Entity,
+    /// 재장전 후 탄 수
+    pub ammo: u32,
+    /// 소모한 AP
+    pub ap_spent: u32,
+}
+
+/// 재장전 실행 결과(거부)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReloadRejected {
+    /// 선택 유닛이 없으면 None
+    pub entity: Option<Entity>,
+    pub reason: ReloadRejectReason,
+}
+
+/// 재장전 거부 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReloadRejectReason {
+    /// 선택된 유닛이 없음(또는 유닛이 아님)
+    NoUnitSelected,
+    /// 활성 팀 페이즈가 아닌 유닛
+    NotActiveTeam,
+    /// 탄창이 없는 유닛(탄 제한 없음)
+    NoMagazine,
+    /// 이미 가득 참
+    AlreadyFull,
+    /// AP 부족
+    OutOfActions { needed: u32, available: u32 },
+}
@@ -0,0 +1,21 @@
+// src/gameplay/commands/mod.rs
+pub mod events;
+pub mod resources;
+pub mod targeting;
+pub mod plugin;
+
+pub mod systems {
+    pub mod move_to;
+    pub mod attack;
+    pub mod reload;
+    pub mod preview;
+}
+
+pub use plugin::{CommandsPlugin, CommandsSet};
+pub use resources::AttackPreview;
+pub use targeting::{AttackAim, evaluate_attack};
+pub use events::{
+    MoveApplied, MoveRejected, MoveRejectReason,
+    AttackResolved, AttackRejected, AttackRejectReason,
+    ReloadApplied, ReloadRejected, ReloadRejectReason,
+};
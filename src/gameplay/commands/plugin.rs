@@ -0,0 +1,51 @@
+// src/gameplay/commands/plugin.rs
+use bevy::prelude::*;
+
+use crate::app::state::AppState;
+use crate::gameplay::interaction::InteractionSet;
+
+use super::{
+    events::{MoveApplied, MoveRejected, AttackResolved, AttackRejected, ReloadApplied, ReloadRejected},
+    resources::AttackPreview,
+    systems::{
+        move_to::execute_move_to, attack::execute_attack_tile, reload::execute_reload,
+        preview::update_attack_preview,
+    },
+};
+
+/// 명령 실행 순서 정의
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommandsSet {
+    /// CommandRequested 소비 → 검증/실행 → 결과 이벤트 발행
+    Execute,
+}
+
+/// CommandRequested(상호작용의 최종 산물)를 실제 게임 상태로 반영하는 실행기
+pub struct CommandsPlugin;
+
+impl Plugin for CommandsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<AttackPreview>()
+            // 결과 이벤트
+            .add_event::<MoveApplied>()
+            .add_event::<MoveRejected>()
+            .add_event::<AttackResolved>()
+            .add_event::<AttackRejected>()
+            .add_event::<ReloadApplied>()
+            .add_event::<ReloadRejected>()
+            // 순서: 상호작용 해석(Interpret) → 실행(Execute)
+            .configure_sets(Update, CommandsSet::Execute.after(InteractionSet::Interpret))
+            .add_systems(
+                Update,
+                (
+                    execute_move_to,
+                    execute_attack_tile,
+                    execute_reload,
+                    update_attack_preview.after(execute_attack_tile),
+                )
+                    .in_set(CommandsSet::Execute)
+                    .run_if(in_state(AppState::Battle)),
+            );
+    }
+}
@@ -0,0 +1,15 @@
+// src/gameplay/commands/resources.rs
+use bevy::prelude::*;
+
+use crate::gameplay::tiles::components::GridPos;
+
+use super::{events::AttackRejectReason, targeting::AttackAim};
+
+/// 조준 미리보기 — 공격 의도(PlayerIntent::Attack) 중 hover 타일을 실행기와 같은 규칙으로 검증한 결과
+/// 공격 의도가 아니거나 hover/선택 유닛이 없으면 비어 있음
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttackPreview {
+    pub attacker: Option<Entity>,
+    pub at: Option<GridPos>,
+    pub result: Option<Result<AttackAim, AttackRejectReason>>,
+}
@@ -0,0 +1,169 @@
+// src/gameplay/commands/systems/attack.rs
+use bevy::prelude::*;
+
+use crate::gameplay::commands::events::{AttackRejected, AttackRejectReason, AttackResolved};
+use crate::gameplay::combat::generate_projectiles;
+use crate::gameplay::commands::targeting::{AttackTargeting, evaluate_attack};
+use crate::gameplay::interaction::{CommandRequested, SelectionCtx};
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::turns::TurnGuard;
+use crate::gameplay::units::components::{ActionBudget, Attack, FiringMods, Magazine, Unit};
+
+type Attacker = (
+    &'static GridPos,
+    &'static Attack,
+    Option<&'static FiringMods>,
+    &'static mut ActionBudget,
+    Option<&'static mut Magazine>,
+);
+
+/// CommandRequested::AttackTile 실행기
+/// - 행동 주체: SelectionCtx.selected_unit (활성 팀 유닛만)
+/// - 검증: targeting::evaluate_attack (조준선 → 사거리 → 곡사 최소 사거리 → 사선 → 대상) → 탄 → AP
+/// - 발사: FiringMods로 투사체를 여기서 한 번만 생성해 AttackResolved에 실어 보냄(전투 해석은 그대로 사용)
+/// - 소모: AP(Attack::ap_cost) + 탄(Magazine이 있을 때만, 투사체 1발당 1탄), 이번 페이즈 공격 표시
+/// - 탄이 모자라면 남은 탄만큼만 발사(생성 순서 앞쪽부터), 비어 있으면 거부
+/// - 대상: GridIndex::get_pos(at)
+pub fn execute_attack_tile(
+    mut ev_cmd: EventReader<CommandRequested>,
+    sel: Res<SelectionCtx>,
+    guard: TurnGuard,
+    targeting: AttackTargeting,
+    mut q_units: Query<Attacker, With<Unit>>,
+    mut ev_resolved: EventWriter<AttackResolved>,
+    mut ev_rejected: EventWriter<AttackRejected>,
+) {
+    for cmd in ev_cmd.read().copied() {
+        let CommandRequested::AttackTile { at } = cmd else { continue; };
+
+        let reject = |attacker: Option<Entity>, reason: AttackRejectReason| AttackRejected { attacker, at, reason };
+
+        // 1) 행동 주체
+        let Some((attacker, from, attack, firing)) = sel
+            .selected_unit
+            .and_then(|e| q_units.get(e).ok().map(|(&p, &a, f, ..)| (e, p, a, f.copied().unwrap_or_default())))
+        else {
+            ev_rejected.write(reject(None, AttackRejectReason::NoUnitSelected));
+            continue;
+        };
+        if !guard.can_act(attacker) {
+            ev_rejected.write(reject(Some(attacker), AttackRejectReason::NotActiveTeam));
+            continue;
+        }
+
+        // 2) 조준 검증(미리보기와 같은 규칙)
+        let (Some(map), Some(index)) = (targeting.map.as_deref(), targeting.index.as_deref()) else {
+            ev_rejected.write(reject(Some(attacker), AttackRejectReason::MapNotReady));
+            continue;
+        };
+        let lobbed_min = targeting.lobbed_min(firing.lobbed);
+        let aim = match evaluate_attack(map, index, from, at, &attack, lobbed_min, |e| q_units.contains(e)) {
+            Ok(aim) => aim,
+            Err(reason) => {
+                ev_rejected.write(reject(Some(attacker), reason));
+                continue;
+            }
+        };
+
+        // 3) 탄 → AP 차감
+        let Ok((_, _, _, mut budget, magazine)) = q_units.get_mut(attacker) else {
+            ev_rejected.write(reject(Some(attacker), AttackRejectReason::NoUnitSelected));
+            continue;
+        };
+        if magazine.as_deref().is_some_and(|m| m.is_empty()) {
+            ev_rejected.write(reject(Some(attacker), AttackRejectReason::OutOfAmmo));
+            continue;
+        }
+        let available = budget.current;
+        if !budget.try_spend(attack.ap_cost) {
+            ev_rejected.write(reject(Some(attacker), AttackRejectReason::OutOfActions { needed: attack.ap_cost, available }));
+            continue;
+        }
+        budget.attacked = true;
+        let mut projectiles = generate_projectiles(map, from, at, aim.dir, aim.distance, &firing, attack.dirs);
+        if let Some(mut magazine) = magazine {
+            let rounds = (projectiles.len() as u32).min(magazine.current);
+            magazine.try_consume(rounds);
+            projectiles.truncate(rounds as usize);
+        }
+
+        ev_resolved.write(AttackResolved {
+            attacker,
+            from,
+            at,
+            target: aim.target,
+            dir: aim.dir,
+            distance: aim.distance,
+            ap_spent: attack.ap_cost,
+            projectiles,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::gameplay::combat::resources::CombatConfig;
+    use crate::gameplay::tiles::components::TerrainKind;
+    use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+
+    /// 10x10 평지: (2,5) 사수(멀티샷 3발, AP 2, 탄창 current/5) → (4,5) 대상
+    fn world(ammo: u32) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Events<CommandRequested>>();
+        world.init_resource::<Events<AttackResolved>>();
+        world.init_resource::<Events<AttackRejected>>();
+        world.init_resource::<CombatConfig>();
+
+        let firing = FiringMods { multishot_rays: 3, ..FiringMods::default() };
+        let magazine = Magazine { current: ammo, ..Magazine::new(5, 1) };
+        let attack = Attack { range: 5, ..Attack::default() };
+        let shooter = world.spawn((Unit, GridPos::new(2, 5), attack, firing, ActionBudget::new(2), magazine)).id();
+        let target = world.spawn((Unit, GridPos::new(4, 5), Attack::default(), ActionBudget::new(2))).id();
+
+        let mut index = GridIndex::new(10, 10);
+        index.place(2, 5, shooter).unwrap();
+        index.place(4, 5, target).unwrap();
+        world.insert_resource(index);
+        world.insert_resource(BaseTileMap::filled(10, 10, TerrainKind::Ground));
+        world.insert_resource(SelectionCtx { selected_unit: Some(shooter), ..SelectionCtx::default() });
+        (world, shooter)
+    }
+
+    fn attack(world: &mut World) -> (Vec<AttackResolved>, Vec<AttackRejected>) {
+        world.send_event(CommandRequested::AttackTile { at: GridPos::new(4, 5) });
+        world.run_system_once(execute_attack_tile).unwrap();
+        world.resource_mut::<Events<CommandRequested>>().clear();
+        let resolved = world.resource_mut::<Events<AttackResolved>>().drain().collect();
+        let rejected = world.resource_mut::<Events<AttackRejected>>().drain().collect();
+        (resolved, rejected)
+    }
+
+    #[test]
+    fn each_generated_projectile_spends_one_round() {
+        let (mut world, shooter) = world(5);
+        let (resolved, _) = attack(&mut world);
+        assert_eq!(resolved[0].projectiles.len(), 3);
+        assert_eq!(world.get::<Magazine>(shooter).unwrap().current, 2);
+        assert_eq!(world.get::<ActionBudget>(shooter).unwrap().current, 1);
+    }
+
+    #[test]
+    fn short_magazine_fires_only_remaining_rounds() {
+        let (mut world, shooter) = world(2);
+        let (resolved, _) = attack(&mut world);
+        assert_eq!(resolved[0].projectiles.len(), 2);
+        assert_eq!(world.get::<Magazine>(shooter).unwrap().current, 0);
+    }
+
+    #[test]
+    fn empty_magazine_is_rejected_without_spending_ap() {
+        let (mut world, shooter) = world(0);
+        let (resolved, rejected) = attack(&mut world);
+        assert!(resolved.is_empty());
+        assert_eq!(rejected[0].reason, AttackRejectReason::OutOfAmmo);
+        assert_eq!(world.get::<ActionBudget>(shooter).unwrap().current, 2);
+    }
+}
@@ -0,0 +1,88 @@
+// src/gameplay/commands/systems/move_to.rs
+use bevy::prelude::*;
+
+use crate::gameplay::commands::events::{MoveApplied, MoveRejected, MoveRejectReason};
+use crate::gameplay::interaction::{CommandRequested, SelectionCtx};
+use crate::gameplay::tiles::api::TilesApi;
+use crate::gameplay::turns::TurnGuard;
+use crate::gameplay::units::movement::UnitMovement;
+
+/// CommandRequested::MoveTo 실행기
+/// - 행동 주체: SelectionCtx.selected_unit (활성 팀 유닛만)
+/// - 검증: 공격 후 이동 제한(ActionTraits::can_move_after_attack) → 현재 MoveBudget 기준 도달 가능 영역(Dijkstra)
+/// - 통과: ActionTraits::can_phase면 점유 칸을 지나갈 수 있음(도착 칸은 비어 있어야 함)
+/// - 비용: 경로의 지형 비용 합만큼 MoveBudget 차감
+/// - 반영: TilesApi로 GridMove 발행(실제 적용은 다음 PreUpdate의 tiles::systems)
+pub fn execute_move_to(
+    mut ev_cmd: EventReader<CommandRequested>,
+    sel: Res<SelectionCtx>,
+    guard: TurnGuard,
+    mut tiles: TilesApi,
+    mut movement: UnitMovement,
+    mut ev_applied: EventWriter<MoveApplied>,
+    mut ev_rejected: EventWriter<MoveRejected>,
+) {
+    for cmd in ev_cmd.read().copied() {
+        let CommandRequested::MoveTo { to } = cmd else { continue; };
+
+        let reject = |entity: Option<Entity>, reason: MoveRejectReason| MoveRejected { entity, to, reason };
+
+        // 1) 행동 주체
+        let Some((entity, from)) = sel
+            .selected_unit
+            .and_then(|e| movement.position(e).map(|p| (e, p)))
+        else {
+            ev_rejected.write(reject(None, MoveRejectReason::NoUnitSelected));
+            continue;
+        };
+        if !guard.can_act(entity) {
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::NotActiveTeam));
+            continue;
+        }
+
+        // 2) 맵 기준 검증
+        let Some(in_bounds) = tiles.in_bounds(to) else {
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::MapNotReady));
+            continue;
+        };
+        if !in_bounds {
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::OutOfBounds));
+            continue;
+        }
+        if from == to {
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::SameCell));
+            continue;
+        }
+        if movement.locked_after_attack(entity) {
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::AlreadyAttacked));
+            continue;
+        }
+
+        // 3) 도달 가능 영역 검증 → 실패 시 사유 판별
+        let Some(reach) = movement.reachable(entity) else {
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::MapNotReady));
+            continue;
+        };
+        let Some(path) = reach.path_to(to) else {
+            let opts = movement.options(entity).unwrap_or_default();
+            let reason = if !movement.pathfinder.is_valid_goal(to, &opts) {
+                MoveRejectReason::Blocked
+            } else if let Some(full) = movement.path_to(entity, to) {
+                MoveRejectReason::OutOfBudget { needed: full.cost, available: reach.budget }
+            } else {
+                MoveRejectReason::NoPath
+            };
+            ev_rejected.write(reject(Some(entity), reason));
+            continue;
+        };
+
+        // 4) 예산 차감 + 실행
+        if !movement.try_spend(entity, path.cost) {
+            let available = movement.budget(entity).unwrap_or(0);
+            ev_rejected.write(reject(Some(entity), MoveRejectReason::OutOfBudget { needed: path.cost, available }));
+            continue;
+        }
+        tiles.move_to(entity, from, to);
+        ev_applied.write(MoveApplied { entity, from, to, path: path.steps, cost: path.cost });
+    }
+}
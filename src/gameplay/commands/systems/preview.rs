@@ -0,0 +1,50 @@
+// src/gameplay/commands/systems/preview.rs
+use bevy::prelude::*;
+
+use crate::gameplay::commands::{
+    resources::AttackPreview,
+    targeting::{AttackTargeting, evaluate_attack},
+};
+use crate::gameplay::interaction::{PlayerIntent, SelectionCtx};
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::commands::events::AttackRejectReason;
+use crate::gameplay::units::components::{Attack, FiringMods, Magazine, Unit};
+
+/// 미리보기에 필요한 공격자 컴포넌트
+type PreviewSource = (
+    &'static GridPos,
+    &'static Attack,
+    Option<&'static FiringMods>,
+    Option<&'static Magazine>,
+);
+
+/// 공격 의도 중 hover 타일 → AttackPreview 갱신 (값이 바뀔 때만 써서 Changed 감지 유지)
+/// - 조준 규칙이 통과해도 탄창이 비었으면 OutOfAmmo
+pub fn update_attack_preview(
+    sel: Res<SelectionCtx>,
+    targeting: AttackTargeting,
+    q_units: Query<PreviewSource, With<Unit>>,
+    mut preview: ResMut<AttackPreview>,
+) {
+    let (map, index) = (targeting.map.as_deref(), targeting.index.as_deref());
+    let next = match (sel.intent, sel.selected_unit, sel.hover_tile, map, index) {
+        (PlayerIntent::Attack, Some(attacker), Some(at), Some(map), Some(index)) => {
+            match q_units.get(attacker) {
+                Ok((&from, attack, firing, magazine)) => {
+                    let lobbed_min = targeting.lobbed_min(firing.is_some_and(|f| f.lobbed));
+                    let result = evaluate_attack(map, index, from, at, attack, lobbed_min, |e| q_units.contains(e))
+                        .and_then(|aim| match magazine {
+                            Some(m) if m.is_empty() => Err(AttackRejectReason::OutOfAmmo),
+                            _ => Ok(aim),
+                        });
+                    AttackPreview { attacker: Some(attacker), at: Some(at), result: Some(result) }
+                }
+                Err(_) => AttackPreview::default(),
+            }
+        }
+        _ => AttackPreview::default(),
+    };
+    if *preview != next {
+        *preview = next;
+    }
+}
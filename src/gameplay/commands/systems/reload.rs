@@ -0,0 +1,108 @@
+// src/gameplay/commands/systems/reload.rs
+use bevy::prelude::*;
+
+use crate::gameplay::commands::events::{ReloadApplied, ReloadRejected, ReloadRejectReason};
+use crate::gameplay::interaction::{CommandRequested, SelectionCtx};
+use crate::gameplay::turns::TurnGuard;
+use crate::gameplay::units::components::{ActionBudget, Magazine, Unit};
+
+/// CommandRequested::Reload 실행기
+/// - 행동 주체: SelectionCtx.selected_unit (활성 팀 유닛만)
+/// - 검증: Magazine 보유 → 가득 차지 않음 → AP(reload_cost)
+/// - 반영: 탄창 가득 채움
+pub fn execute_reload(
+    mut ev_cmd: EventReader<CommandRequested>,
+    sel: Res<SelectionCtx>,
+    guard: TurnGuard,
+    mut q_units: Query<(&mut ActionBudget, Option<&mut Magazine>), With<Unit>>,
+    mut ev_applied: EventWriter<ReloadApplied>,
+    mut ev_rejected: EventWriter<ReloadRejected>,
+) {
+    for cmd in ev_cmd.read().copied() {
+        let CommandRequested::Reload = cmd else { continue; };
+
+        let reject = |entity: Option<Entity>, reason: ReloadRejectReason| ReloadRejected { entity, reason };
+
+        // 1) 행동 주체
+        let Some((entity, (mut budget, magazine))) = sel
+            .selected_unit
+            .and_then(|e| q_units.get_mut(e).ok().map(|q| (e, q)))
+        else {
+            ev_rejected.write(reject(None, ReloadRejectReason::NoUnitSelected));
+            continue;
+        };
+        if !guard.can_act(entity) {
+            ev_rejected.write(reject(Some(entity), ReloadRejectReason::NotActiveTeam));
+            continue;
+        }
+
+        // 2) 탄창 검증
+        let Some(mut magazine) = magazine else {
+            ev_rejected.write(reject(Some(entity), ReloadRejectReason::NoMagazine));
+            continue;
+        };
+        if magazine.is_full() {
+            ev_rejected.write(reject(Some(entity), ReloadRejectReason::AlreadyFull));
+            continue;
+        }
+
+        // 3) AP 차감 + 재장전
+        let needed = magazine.reload_cost;
+        let available = budget.current;
+        if !budget.try_spend(needed) {
+            ev_rejected.write(reject(Some(entity), ReloadRejectReason::OutOfActions { needed, available }));
+            continue;
+        }
+        magazine.refill();
+        ev_applied.write(ReloadApplied { entity, ammo: magazine.current, ap_spent: needed });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// 탄 0/4, 재장전 AP 2인 유닛을 선택한 월드
+    fn world(ap: u32) -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Events<CommandRequested>>();
+        world.init_resource::<Events<ReloadApplied>>();
+        world.init_resource::<Events<ReloadRejected>>();
+        let magazine = Magazine { current: 0, ..Magazine::new(4, 2) };
+        let unit = world.spawn((Unit, ActionBudget::new(ap), magazine)).id();
+        world.insert_resource(SelectionCtx { selected_unit: Some(unit), ..SelectionCtx::default() });
+        (world, unit)
+    }
+
+    fn reload(world: &mut World) -> (Vec<ReloadApplied>, Vec<ReloadRejected>) {
+        world.send_event(CommandRequested::Reload);
+        world.run_system_once(execute_reload).unwrap();
+        world.resource_mut::<Events<CommandRequested>>().clear();
+        let applied = world.resource_mut::<Events<ReloadApplied>>().drain().collect();
+        let rejected = world.resource_mut::<Events<ReloadRejected>>().drain().collect();
+        (applied, rejected)
+    }
+
+    #[test]
+    fn reload_spends_reload_cost_and_refills() {
+        let (mut world, unit) = world(3);
+        let (applied, _) = reload(&mut world);
+        assert_eq!(applied, [ReloadApplied { entity: unit, ammo: 4, ap_spent: 2 }]);
+        assert_eq!(world.get::<ActionBudget>(unit).unwrap().current, 1);
+
+        // 가득 찬 탄창은 거부
+        let (_, rejected) = reload(&mut world);
+        assert_eq!(rejected[0].reason, ReloadRejectReason::AlreadyFull);
+    }
+
+    #[test]
+    fn reload_without_enough_ap_is_rejected() {
+        let (mut world, unit) = world(1);
+        let (applied, rejected) = reload(&mut world);
+        assert!(applied.is_empty());
+        assert_eq!(rejected[0].reason, ReloadRejectReason::OutOfActions { needed: 2, available: 1 });
+        assert!(world.get::<Magazine>(unit).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+// src/gameplay/commands/targeting.rs
+//! 공격 조준 검증 — 실행기(execute_attack_tile)와 조준 미리보기(AttackPreview)가 같은 규칙을 공유
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::gameplay::combat::{resources::CombatConfig, targeting::line_of_fire};
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::tiles::resources::{BaseTileMap, GridIndex};
+use crate::gameplay::units::components::Attack;
+
+use super::events::AttackRejectReason;
+
+/// 조준 검증 통과 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttackAim {
+    /// 단위 조준 방향
+    pub dir: (i32, i32),
+    /// 조준 거리(칸)
+    pub distance: u32,
+    /// 조준 타일의 대상 유닛
+    pub target: Entity,
+}
+
+/// 조준 검증 공용 파라미터: 맵/점유 + 전투 규칙
+#[derive(SystemParam)]
+pub struct AttackTargeting<'w> {
+    pub map: Option<Res<'w, BaseTileMap>>,
+    pub index: Option<Res<'w, GridIndex>>,
+    config: Res<'w, CombatConfig>,
+}
+
+impl AttackTargeting<'_> {
+    /// evaluate_attack에 넘길 곡사 최소 사거리 (직사면 None)
+    #[inline]
+    pub fn lobbed_min(&self, lobbed: bool) -> Option<u32> {
+        lobbed.then_some(self.config.lobbed_min_range)
+    }
+}
+
+/// from에서 at을 공격할 수 있는지 (AP/턴 검사는 제외)
+/// - 순서: 맵 범위 → 자기 칸 → 조준선(AimDirs) → 사거리 → 곡사 최소 사거리 → 사선 → 대상
+/// - `lobbed_min`: 곡사면 최소 사거리(CombatConfig::lobbed_min_range), 직사면 None
+/// - `is_unit`: 조준 타일 점유자가 공격 가능한 유닛인지
+pub fn evaluate_attack(
+    map: &BaseTileMap,
+    index: &GridIndex,
+    from: GridPos,
+    at: GridPos,
+    attack: &Attack,
+    lobbed_min: Option<u32>,
+    is_unit: impl Fn(Entity) -> bool,
+) -> Result<AttackAim, AttackRejectReason> {
+    if !map.in_bounds_pos(at) {
+        return Err(AttackRejectReason::OutOfBounds);
+    }
+    if from == at {
+        return Err(AttackRejectReason::SameCell);
+    }
+    let Some((dir, distance)) = attack.aim_line(from, at) else {
+        return Err(AttackRejectReason::NotInLine);
+    };
+    if distance > attack.range {
+        return Err(AttackRejectReason::OutOfRange { distance, range: attack.range });
+    }
+    if let Some(min) = lobbed_min.filter(|&min| distance < min) {
+        return Err(AttackRejectReason::TooClose { distance, min });
+    }
+    let lof = line_of_fire(map, |p| index.get_pos(p), from, at, lobbed_min.is_some());
+    if !lof.is_clear() {
+        return Err(AttackRejectReason::LineBlocked { at: lof.impact(at) });
+    }
+    let Some(target) = index.get_pos(at).filter(|&e| is_unit(e)) else {
+        return Err(AttackRejectReason::NoTarget);
+    };
+    Ok(AttackAim { dir, distance, target })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::tiles::components::TerrainKind;
+
+    /// 10x10 평지, (2,5) 사수 / (3,5)·(6,5) 대상 / (4,5) 벽
+    fn board() -> (BaseTileMap, GridIndex) {
+        let mut map = BaseTileMap::filled(10, 10, TerrainKind::Ground);
+        let i = map.idx(4, 5);
+        map.terrain[i] = TerrainKind::Wall;
+        let mut index = GridIndex::new(10, 10);
+        for (id, x) in [(1, 2), (2, 3), (3, 6)] {
+            index.place(x, 5, Entity::from_raw(id)).unwrap();
+        }
+        (map, index)
+    }
+
+    fn aim(at: (u32, u32), lobbed_min: Option<u32>) -> Result<AttackAim, AttackRejectReason> {
+        let (map, index) = board();
+        let attack = Attack { range: 5, ..Attack::default() };
+        evaluate_attack(&map, &index, GridPos::new(2, 5), GridPos::new(at.0, at.1), &attack, lobbed_min, |_| true)
+    }
+
+    #[test]
+    fn lobbed_fire_is_rejected_below_min_range() {
+        assert_eq!(aim((3, 5), Some(2)), Err(AttackRejectReason::TooClose { distance: 1, min: 2 }));
+        // 직사는 최소 사거리 없음
+        assert_eq!(aim((3, 5), None).map(|a| a.target), Ok(Entity::from_raw(2)));
+    }
+
+    #[test]
+    fn lobbed_fire_clears_wall_that_blocks_direct_fire() {
+        assert_eq!(aim((6, 5), None), Err(AttackRejectReason::LineBlocked { at: GridPos::new(3, 5) }));
+        assert_eq!(aim((6, 5), Some(2)).map(|a| (a.target, a.distance)), Ok((Entity::from_raw(3), 4)));
+        // 최소 사거리는 규칙값을 그대로 따름
+        assert_eq!(aim((6, 5), Some(5)), Err(AttackRejectReason::TooClose { distance: 4, min: 5 }));
+    }
+}
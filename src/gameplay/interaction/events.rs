@@ -50,5 +50,7 @@ pub enum CommandRequested {
     MoveTo { to: GridPos },
     /// 공격도 우선 타일 기준(대상 유닛 resolve는 executor/인덱스가 수행)
     AttackTile { at: GridPos },
+    /// 선택 유닛 재장전 (AP 소모)
+    Reload,
     EndTurn,
 }
@@ -9,9 +9,10 @@ pub mod systems {
     pub mod hotkeys;
     pub mod clicks;
     pub mod cancel;
+    pub mod roster;
 }
 
-pub use plugin::InteractionPlugin;
+pub use plugin::{InteractionPlugin, InteractionSet};
 pub use state::PlayerIntent;
 pub use resources::SelectionCtx;  // ⬅️ 여기서 re-export
 pub use events::{TileHovered, TileClicked, HoverOutside, ClickOutside,
@@ -17,13 +17,14 @@ use super::{
         hotkeys::handle_hotkeys_to_intent,
         clicks::handle_clicks_by_intent,
         cancel::cancel_on_right_click,  
+        roster::cycle_squad_selection,
     },
 };
 
 pub struct InteractionPlugin;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
-enum InteractionSet {
+pub enum InteractionSet {
     /// input(world) → grid pos 이벤트 변환
     MapInput,
     /// hover/핫키/클릭 해석
@@ -60,7 +61,7 @@ impl Plugin for InteractionPlugin {
             // 2) hover 적용 / 핫키 해석 / 클릭 해석
             .add_systems(
                 Update,
-                (cancel_on_right_click, apply_hover_to_ctx, handle_hotkeys_to_intent, handle_clicks_by_intent)
+                (cancel_on_right_click, apply_hover_to_ctx, handle_hotkeys_to_intent, handle_clicks_by_intent, cycle_squad_selection)
                     .in_set(InteractionSet::Interpret)
                     .run_if(in_state(AppState::Battle)),
             );
@@ -4,14 +4,15 @@ use crate::input::KeyJustPressed;
 use crate::gameplay::interaction::{
     resources::SelectionCtx,
     state::PlayerIntent,
-    events::PlayerIntentChanged,
+    events::{PlayerIntentChanged, CommandRequested},
 };
 
-/// 핫키 → 의도 전환 (실행/검증은 다른 시스템에서 처리)
+/// 핫키 → 의도 전환 / 재장전·턴 종료 요청 (실행/검증은 다른 시스템에서 처리)
 pub fn handle_hotkeys_to_intent(
     mut ev_keys: EventReader<KeyJustPressed>,
     mut sel: ResMut<SelectionCtx>,
     mut ev_changed: EventWriter<PlayerIntentChanged>,
+    mut ev_cmd: EventWriter<CommandRequested>,
 ) {
     // 기본은 현재 의도 유지, 마지막으로 눌린 키를 우선
     let mut target = sel.intent;
@@ -29,6 +30,16 @@ pub fn handle_hotkeys_to_intent(
                 // let (_t, _u) = sel.clear_selection();
             }
 
+            // 재장전 (선택 유닛)
+            KeyCode::KeyR => {
+                ev_cmd.write(CommandRequested::Reload);
+            }
+
+            // 턴 종료
+            KeyCode::Space => {
+                ev_cmd.write(CommandRequested::EndTurn);
+            }
+
             _ => {}
         }
     }
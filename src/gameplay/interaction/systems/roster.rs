@@ -0,0 +1,36 @@
+// src/gameplay/interaction/systems/roster.rs
+use bevy::prelude::*;
+
+use crate::input::{CycleBackPressed, KeyJustPressed};
+use crate::gameplay::interaction::{events::SelectionChanged, resources::SelectionCtx};
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::units::{
+    components::{ActionBudget, MoveBudget, Unit},
+    resources::SquadRoster,
+};
+
+/// Tab / Shift+Tab → 분대 명단에서 이동력이나 AP가 남은 다음/이전 유닛 선택
+/// - Tab은 KeyJustPressed, Shift+Tab은 입력 계층이 따로 보낸 CycleBackPressed
+/// - 숫자키 1/2는 이동/공격 의도라 순환에는 Tab만 사용
+/// - 남은 유닛이 없으면 선택 유지. 의도(모드)는 바꾸지 않음
+pub fn cycle_squad_selection(
+    mut ev_keys: EventReader<KeyJustPressed>,
+    mut ev_cycle_back: EventReader<CycleBackPressed>,
+    roster: Res<SquadRoster>,
+    q_units: Query<(&GridPos, &MoveBudget, &ActionBudget), With<Unit>>,
+    mut sel: ResMut<SelectionCtx>,
+    mut ev_sel_changed: EventWriter<SelectionChanged>,
+) {
+    let forward = ev_keys.read().filter(|k| k.0 == KeyCode::Tab).map(|_| false);
+    let back = ev_cycle_back.read().map(|_| true);
+    for backward in forward.chain(back) {
+        let ready = |e: Entity| q_units.get(e).is_ok_and(|(_, mv, ap)| mv.current > 0 || ap.current > 0);
+        let Some(next) = roster.cycle(sel.selected_unit, backward, ready) else { continue; };
+        let Ok((pos, ..)) = q_units.get(next) else { continue; };
+
+        let (tile_changed, unit_changed) = sel.set_selection(Some(*pos), Some(next));
+        if tile_changed || unit_changed {
+            ev_sel_changed.write(SelectionChanged { tile_changed, unit_changed });
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod tiles;
 pub mod interaction;
 pub mod units;
+pub mod commands;
+pub mod combat;
+pub mod turns;
+pub mod perks;
 
 pub mod plugin;
 pub use plugin::GameplayPlugin;
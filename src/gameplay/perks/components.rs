@@ -0,0 +1,52 @@
+// src/gameplay/perks/components.rs
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// 퍼크 식별자 (레지스트리 RON의 키)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct PerkId(pub String);
+
+impl PerkId {
+    pub fn new(id: impl Into<String>) -> Self { Self(id.into()) }
+    #[inline] pub fn as_str(&self) -> &str { &self.0 }
+}
+
+/// 장착한 퍼크 1개
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignedPerk {
+    pub id: PerkId,
+    /// 1부터
+    pub level: u8,
+}
+
+/// 유닛의 퍼크 슬롯
+/// - unlocked: 장착 가능한 퍼크 수(레벨업으로 증가)
+/// - perks: 장착 순서대로. 같은 퍼크 재획득은 슬롯을 더 쓰지 않고 레벨업
+/// - 슬롯 종류별 배타 규칙은 rules::validate
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PerkSlots {
+    pub unlocked: u8,
+    pub perks: Vec<AssignedPerk>,
+}
+
+impl PerkSlots {
+    pub fn new(unlocked: u8) -> Self { Self { unlocked, perks: Vec::new() } }
+
+    /// 장착 중인 퍼크의 레벨
+    pub fn level_of(&self, id: &PerkId) -> Option<u8> {
+        self.perks.iter().find(|p| p.id == *id).map(|p| p.level)
+    }
+
+    #[inline]
+    pub fn has_free_slot(&self) -> bool {
+        self.perks.len() < self.unlocked as usize
+    }
+
+    /// 새로 장착하거나 레벨 갱신
+    pub fn set(&mut self, id: PerkId, level: u8) {
+        match self.perks.iter_mut().find(|p| p.id == id) {
+            Some(p) => p.level = level,
+            None => self.perks.push(AssignedPerk { id, level }),
+        }
+    }
+}
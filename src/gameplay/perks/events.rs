@@ -0,0 +1,29 @@
+// src/gameplay/perks/events.rs
+use bevy::prelude::*;
+
+use super::{components::PerkId, registry::PerkSlot, rules::PerkRejectReason};
+
+/// 퍼크 장착 요청 (보상 선택 UI 등) — 이미 가진 퍼크면 레벨업 요청
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct PerkAssignRequested {
+    pub unit: Entity,
+    pub perk: PerkId,
+}
+
+/// 퍼크 장착 확정 (컴포넌트 반영 후)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct PerkAssignedApplied {
+    pub unit: Entity,
+    pub perk: PerkId,
+    pub slot: PerkSlot,
+    /// 적용된 레벨(1 = 신규 장착)
+    pub level: u8,
+}
+
+/// 퍼크 장착 거부
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct PerkAssignRejected {
+    pub unit: Entity,
+    pub perk: PerkId,
+    pub reason: PerkRejectReason,
+}
@@ -0,0 +1,13 @@
+// src/gameplay/perks/mod.rs
+pub mod components;
+pub mod registry;
+pub mod rules;
+pub mod events;
+pub mod systems;
+pub mod plugin;
+
+pub use plugin::PerksPlugin;
+pub use components::{AssignedPerk, PerkId, PerkSlots};
+pub use registry::{PerkDef, PerkEffect, PerkLevel, PerkRegistry, PerkSlot};
+pub use rules::{PerkRejectReason, apply_effect, validate};
+pub use events::{PerkAssignRequested, PerkAssignedApplied, PerkAssignRejected};
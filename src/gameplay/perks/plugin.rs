@@ -0,0 +1,33 @@
+// src/gameplay/perks/plugin.rs
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+use crate::app::state::AppState;
+
+use super::{
+    events::{PerkAssignRejected, PerkAssignRequested, PerkAssignedApplied},
+    registry::{PerkRegistry, PerkTable, PerkTableHandle},
+    systems::{apply_perk_registry, apply_perk_requests, load_perk_registry},
+};
+
+/// 퍼크: 레지스트리(RON) + 슬롯 규칙 검증/적용
+pub struct PerksPlugin;
+
+impl Plugin for PerksPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // 퍼크 레지스트리 (*.perks.ron)
+            .add_plugins(RonAssetPlugin::<PerkTable>::new(&["perks.ron"]))
+            .init_resource::<PerkRegistry>()
+            .init_resource::<PerkTableHandle>()
+            .add_event::<PerkAssignRequested>()
+            .add_event::<PerkAssignedApplied>()
+            .add_event::<PerkAssignRejected>()
+            .add_systems(Startup, load_perk_registry)
+            .add_systems(Update, apply_perk_registry)
+            .add_systems(
+                Update,
+                apply_perk_requests.run_if(in_state(AppState::Battle)),
+            );
+    }
+}
@@ -0,0 +1,187 @@
+// src/gameplay/perks/registry.rs
+//! 퍼크 레지스트리: RON 정의(레벨별 수치/설명) → 런타임 PerkRegistry
+use std::collections::HashMap;
+
+use bevy::{asset::Asset, prelude::*, reflect::TypePath};
+use serde::{Deserialize, Deserializer};
+
+use crate::gameplay::units::{components::Element, spec::ElementSpec};
+
+use super::components::PerkId;
+
+/// 기본 레지스트리 경로 (assets/ 기준)
+pub const PERK_REGISTRY_PATH: &str = "rules/base.perks.ron";
+
+/// 퍼크가 차지하는 슬롯 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PerkSlot {
+    /// 전파: 관통/도탄/연쇄 중 하나만
+    Propagation,
+    /// OnHit: 종류가 다르면 중첩(폭발 + 넉백 + 중력)
+    OnHit,
+    /// 속성: 하나만
+    Element,
+    /// 발사 패턴(연사/멀티샷/부채꼴/곡사): 종류별 하나
+    Pattern,
+    /// 행동 특성 플래그: 종류별 하나
+    Trait,
+}
+
+/// 퍼크 효과 (레벨마다 절대값 — 레벨업은 덮어쓰기). RON에서도 그대로 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum PerkEffect {
+    Pierce { times: u8 },
+    Ricochet { times: u8, branches: u8 },
+    Chain { jumps: u8, radius: u8, diagonal: bool },
+    Explode { radius: u8, bonus_damage: i32, every_hit: bool },
+    Knockback { distance: u8, impact_damage: i32 },
+    Gravity { range: u8, strength: u8 },
+    Imbue {
+        #[serde(deserialize_with = "element_from_spec")]
+        element: Element,
+        cooldown_max: u32,
+    },
+    Burst { extra: u8 },
+    Multishot { rays: u8 },
+    Fan { rays: u8 },
+    Lobbed,
+    MoveAfterAttack,
+    AutoReload,
+    Phase,
+}
+
+/// RON의 속성 이름(ElementSpec) → Element
+fn element_from_spec<'de, D: Deserializer<'de>>(d: D) -> Result<Element, D::Error> {
+    ElementSpec::deserialize(d).map(Element::from)
+}
+
+impl PerkEffect {
+    pub fn slot(&self) -> PerkSlot {
+        use PerkEffect::*;
+        match self {
+            Pierce { .. } | Ricochet { .. } | Chain { .. } => PerkSlot::Propagation,
+            Explode { .. } | Knockback { .. } | Gravity { .. } => PerkSlot::OnHit,
+            Imbue { .. } => PerkSlot::Element,
+            Burst { .. } | Multishot { .. } | Fan { .. } | Lobbed => PerkSlot::Pattern,
+            MoveAfterAttack | AutoReload | Phase => PerkSlot::Trait,
+        }
+    }
+
+    /// 같은 종류(수치 무관)인지
+    #[inline]
+    pub fn same_kind(&self, other: &PerkEffect) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// 레벨 1개
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerkLevel {
+    pub description: String,
+    pub effect: PerkEffect,
+}
+
+/// 퍼크 정의 — 모든 레벨의 효과 종류가 같음(로드 시 검사)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerkDef {
+    pub name: String,
+    /// levels[0] = 1레벨
+    pub levels: Vec<PerkLevel>,
+}
+
+impl PerkDef {
+    #[inline]
+    pub fn max_level(&self) -> u8 {
+        self.levels.len() as u8
+    }
+
+    /// 1부터 세는 레벨
+    #[inline]
+    pub fn level(&self, level: u8) -> Option<&PerkLevel> {
+        self.levels.get((level as usize).checked_sub(1)?)
+    }
+
+    #[inline]
+    pub fn slot(&self) -> PerkSlot {
+        self.levels[0].effect.slot()
+    }
+
+    #[inline]
+    pub fn effect(&self) -> &PerkEffect {
+        &self.levels[0].effect
+    }
+}
+
+// ---- RON ----
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PerkLevelSpec {
+    pub description: String,
+    pub effect: PerkEffect,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PerkSpec {
+    pub name: String,
+    pub levels: Vec<PerkLevelSpec>,
+}
+
+/// 퍼크 id → 정의 (RON 에셋)
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct PerkTable {
+    pub perks: HashMap<String, PerkSpec>,
+}
+
+/// 런타임 레지스트리: 로드된 PerkTable을 펼친 것 (로드 전에는 비어 있음)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PerkRegistry {
+    perks: HashMap<PerkId, PerkDef>,
+}
+
+impl PerkRegistry {
+    #[inline]
+    pub fn get(&self, id: &PerkId) -> Option<&PerkDef> {
+        self.perks.get(id)
+    }
+
+    /// 레지스트리 구성: 레벨이 없거나 레벨마다 효과 종류가 다른 퍼크는 제외(경고)
+    pub fn from_table(table: &PerkTable) -> Self {
+        let mut perks = HashMap::with_capacity(table.perks.len());
+        for (id, spec) in &table.perks {
+            let levels: Vec<PerkLevel> = spec
+                .levels
+                .iter()
+                .map(|l| PerkLevel { description: l.description.clone(), effect: l.effect })
+                .collect();
+            let Some(first) = levels.first().map(|l| l.effect) else {
+                warn!("perk '{id}': no levels, skipped");
+                continue;
+            };
+            if !levels.iter().all(|l| l.effect.same_kind(&first)) {
+                warn!("perk '{id}': levels mix different effect kinds, skipped");
+                continue;
+            }
+            perks.insert(PerkId::new(id.clone()), PerkDef { name: spec.name.clone(), levels });
+        }
+        Self { perks }
+    }
+}
+
+/// 로드 중인 레지스트리 핸들
+#[derive(Resource, Debug, Default)]
+pub struct PerkTableHandle(pub Handle<PerkTable>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_table_parses_effects_directly() {
+        let table: PerkTable = ron::from_str(include_str!("../../../assets/rules/base.perks.ron")).unwrap();
+        let registry = PerkRegistry::from_table(&table);
+        let cryo = registry.get(&PerkId::new("cryo")).unwrap();
+        assert_eq!(cryo.slot(), PerkSlot::Element);
+        assert_eq!(cryo.level(2).map(|l| l.effect), Some(PerkEffect::Imbue { element: Element::Ice, cooldown_max: 1 }));
+        assert_eq!(registry.get(&PerkId::new("mortar")).map(|d| *d.effect()), Some(PerkEffect::Lobbed));
+    }
+}
@@ -0,0 +1,196 @@
+// src/gameplay/perks/rules.rs
+//! 슬롯 규칙 검증 + 퍼크 효과 적용
+//!
+//! 규칙
+//! - 같은 퍼크 재획득: 슬롯을 더 쓰지 않고 레벨 +1 (최대 레벨이면 거부)
+//! - 새 퍼크: 빈 슬롯(PerkSlots::unlocked) 필요
+//! - 전파(관통/도탄/연쇄): 3중 1 — 다른 전파 퍼크가 있거나 유닛이 원래(스펙) 전파를 가졌으면 거부
+//! - 속성: 1개 — 다른 속성 퍼크가 있거나 유닛이 원래(스펙) 속성을 가졌으면 거부
+//! - OnHit/발사 패턴/특성: 종류가 다르면 중첩, 같은 종류 퍼크가 이미 있으면 거부
+//! - 특성 플래그: 유닛이 원래(스펙) 가진 플래그면 거부
+//!
+//! 적용: 레벨 수치를 해당 컴포넌트 필드에 덮어씀(스펙 기본값보다 우선)
+use crate::gameplay::units::components::{
+    ActionTraits, ElementalImbue, Explode, FiringMods, GravityPull, Knockback, OnHitEffects, Propagation,
+};
+
+use super::{
+    components::{PerkId, PerkSlots},
+    registry::{PerkDef, PerkEffect, PerkRegistry, PerkSlot},
+};
+
+/// 퍼크 장착 거부 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PerkRejectReason {
+    /// 퍼크 슬롯이 없는 엔티티(유닛이 아님 등)
+    NoPerkSlots,
+    /// 레지스트리에 없는 퍼크
+    UnknownPerk,
+    /// 이미 최대 레벨
+    MaxLevel { max: u8 },
+    /// 빈 슬롯 없음
+    NoFreeSlot { unlocked: u8 },
+    /// 배타 슬롯(전파/속성)을 다른 퍼크(by) 또는 유닛 스펙(by = None)이 차지
+    SlotTaken { slot: PerkSlot, by: Option<PerkId> },
+    /// 같은 종류 효과를 다른 퍼크가 이미 제공
+    EffectTaken { by: PerkId },
+    /// 유닛이 원래 가진 특성 플래그
+    TraitAlreadySet,
+}
+
+/// 장착 요청 검증 → 적용할 레벨(1이면 신규, 그 이상이면 레벨업)
+pub fn validate(
+    slots: &PerkSlots,
+    traits_: &ActionTraits,
+    propagation: &Propagation,
+    imbue: &ElementalImbue,
+    registry: &PerkRegistry,
+    id: &PerkId,
+) -> Result<u8, PerkRejectReason> {
+    let def = registry.get(id).ok_or(PerkRejectReason::UnknownPerk)?;
+
+    // 재획득 → 레벨업
+    if let Some(level) = slots.level_of(id) {
+        let max = def.max_level();
+        return if level >= max { Err(PerkRejectReason::MaxLevel { max }) } else { Ok(level + 1) };
+    }
+
+    if !slots.has_free_slot() {
+        return Err(PerkRejectReason::NoFreeSlot { unlocked: slots.unlocked });
+    }
+
+    let slot = def.slot();
+    for held in &slots.perks {
+        let Some(other) = registry.get(&held.id) else { continue; };
+        match slot {
+            PerkSlot::Propagation | PerkSlot::Element if other.slot() == slot => {
+                return Err(PerkRejectReason::SlotTaken { slot, by: Some(held.id.clone()) });
+            }
+            PerkSlot::OnHit | PerkSlot::Pattern | PerkSlot::Trait if other.effect().same_kind(def.effect()) => {
+                return Err(PerkRejectReason::EffectTaken { by: held.id.clone() });
+            }
+            _ => {}
+        }
+    }
+
+    // 보유 퍼크가 없는데 슬롯이 차 있으면 스펙에서 온 것 — 덮어쓰지 않음
+    let spec_filled = match slot {
+        PerkSlot::Propagation => *propagation != Propagation::None,
+        PerkSlot::Element => imbue.element.is_some(),
+        _ => false,
+    };
+    if spec_filled {
+        return Err(PerkRejectReason::SlotTaken { slot, by: None });
+    }
+
+    if slot == PerkSlot::Trait && trait_flag(traits_, def) {
+        return Err(PerkRejectReason::TraitAlreadySet);
+    }
+    Ok(1)
+}
+
+/// 이 특성 퍼크의 플래그가 이미 켜져 있는지
+fn trait_flag(traits_: &ActionTraits, def: &PerkDef) -> bool {
+    match def.effect() {
+        PerkEffect::MoveAfterAttack => traits_.can_move_after_attack,
+        PerkEffect::AutoReload => traits_.auto_reload,
+        PerkEffect::Phase => traits_.can_phase,
+        _ => false,
+    }
+}
+
+/// 퍼크가 바꾸는 유닛 컴포넌트 묶음
+pub struct PerkTargets<'a> {
+    pub propagation: &'a mut Propagation,
+    pub on_hit: &'a mut OnHitEffects,
+    pub imbue: &'a mut ElementalImbue,
+    pub firing: &'a mut FiringMods,
+    pub traits_: &'a mut ActionTraits,
+}
+
+/// 효과 1레벨 적용 (수치 덮어쓰기)
+pub fn apply_effect(effect: PerkEffect, t: PerkTargets) {
+    match effect {
+        PerkEffect::Pierce { times } => *t.propagation = Propagation::Pierce { times },
+        PerkEffect::Ricochet { times, branches } => *t.propagation = Propagation::Ricochet { times, branches },
+        PerkEffect::Chain { jumps, radius, diagonal } => {
+            *t.propagation = Propagation::Chain { jumps, radius, diagonal };
+        }
+        PerkEffect::Explode { radius, bonus_damage, every_hit } => {
+            t.on_hit.explode = Some(Explode { radius, bonus_damage, every_hit });
+        }
+        PerkEffect::Knockback { distance, impact_damage } => {
+            t.on_hit.knockback = Some(Knockback { distance, impact_damage });
+        }
+        PerkEffect::Gravity { range, strength } => t.on_hit.gravity = Some(GravityPull { range, strength }),
+        PerkEffect::Imbue { element, cooldown_max } => {
+            t.imbue.element = Some(element);
+            t.imbue.cooldown_max = cooldown_max;
+            t.imbue.cooldown = t.imbue.cooldown.min(cooldown_max);
+        }
+        PerkEffect::Burst { extra } => t.firing.set_burst(extra),
+        PerkEffect::Multishot { rays } => t.firing.set_multishot(rays),
+        PerkEffect::Fan { rays } => t.firing.set_fan(rays),
+        PerkEffect::Lobbed => t.firing.lobbed = true,
+        PerkEffect::MoveAfterAttack => t.traits_.can_move_after_attack = true,
+        PerkEffect::AutoReload => t.traits_.auto_reload = true,
+        PerkEffect::Phase => t.traits_.can_phase = true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::gameplay::perks::registry::{PerkLevelSpec, PerkSpec, PerkTable};
+    use crate::gameplay::units::components::Element;
+
+    fn registry() -> PerkRegistry {
+        let perk = |effect| PerkSpec {
+            name: String::new(),
+            levels: vec![PerkLevelSpec { description: String::new(), effect }],
+        };
+        let perks = HashMap::from([
+            ("pierce".to_string(), perk(PerkEffect::Pierce { times: 1 })),
+            ("cryo".to_string(), perk(PerkEffect::Imbue { element: Element::Ice, cooldown_max: 1 })),
+        ]);
+        PerkRegistry::from_table(&PerkTable { perks })
+    }
+
+    fn imbue(element: Option<Element>) -> ElementalImbue {
+        ElementalImbue { element, cooldown_max: 0, cooldown: 0 }
+    }
+
+    #[test]
+    fn spec_propagation_blocks_propagation_perk() {
+        let reg = registry();
+        let slots = PerkSlots::new(2);
+        let ricochet = Propagation::Ricochet { times: 1, branches: 2 };
+        let result = validate(&slots, &ActionTraits::default(), &ricochet, &imbue(None), &reg, &PerkId::new("pierce"));
+        assert_eq!(result, Err(PerkRejectReason::SlotTaken { slot: PerkSlot::Propagation, by: None }));
+
+        let result = validate(&slots, &ActionTraits::default(), &Propagation::None, &imbue(None), &reg, &PerkId::new("pierce"));
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn spec_element_blocks_imbue_perk() {
+        let reg = registry();
+        let slots = PerkSlots::new(2);
+        let fire = imbue(Some(Element::Fire));
+        let result = validate(&slots, &ActionTraits::default(), &Propagation::None, &fire, &reg, &PerkId::new("cryo"));
+        assert_eq!(result, Err(PerkRejectReason::SlotTaken { slot: PerkSlot::Element, by: None }));
+    }
+
+    #[test]
+    fn held_perk_relevel_ignores_its_own_slot_value() {
+        // 장착 후엔 컴포넌트가 퍼크 값이지만 같은 퍼크 레벨업은 막지 않음 (max 1이라 MaxLevel)
+        let reg = registry();
+        let mut slots = PerkSlots::new(2);
+        slots.set(PerkId::new("pierce"), 1);
+        let pierce = Propagation::Pierce { times: 1 };
+        let result = validate(&slots, &ActionTraits::default(), &pierce, &imbue(None), &reg, &PerkId::new("pierce"));
+        assert_eq!(result, Err(PerkRejectReason::MaxLevel { max: 1 }));
+    }
+}
@@ -0,0 +1,148 @@
+// src/gameplay/perks/systems.rs
+use bevy::prelude::*;
+
+use crate::gameplay::units::components::{
+    ActionTraits, ElementalImbue, FiringMods, OnHitEffects, Propagation,
+};
+
+use super::{
+    components::PerkSlots,
+    events::{PerkAssignRejected, PerkAssignRequested, PerkAssignedApplied},
+    registry::{PERK_REGISTRY_PATH, PerkRegistry, PerkTable, PerkTableHandle},
+    rules::{PerkRejectReason, PerkTargets, apply_effect, validate},
+};
+
+/// 퍼크가 바꾸는 유닛 컴포넌트
+type PerkHolder = (
+    &'static mut PerkSlots,
+    &'static mut Propagation,
+    &'static mut OnHitEffects,
+    &'static mut ElementalImbue,
+    &'static mut FiringMods,
+    &'static mut ActionTraits,
+);
+
+/// 퍼크 레지스트리 로드 요청
+pub fn load_perk_registry(asset_server: Res<AssetServer>, mut handle: ResMut<PerkTableHandle>) {
+    handle.0 = asset_server.load(PERK_REGISTRY_PATH);
+}
+
+/// 레지스트리 로드/핫리로드 시 런타임 레지스트리 갱신
+pub fn apply_perk_registry(
+    mut ev: EventReader<AssetEvent<PerkTable>>,
+    handle: Res<PerkTableHandle>,
+    tables: Res<Assets<PerkTable>>,
+    mut registry: ResMut<PerkRegistry>,
+) {
+    for e in ev.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = e else { continue; };
+        if *id != handle.0.id() { continue; }
+        if let Some(table) = tables.get(*id) {
+            *registry = PerkRegistry::from_table(table);
+        }
+    }
+}
+
+/// PerkAssignRequested → 슬롯 규칙 검증 → 컴포넌트 반영 + PerkAssignedApplied (실패 시 PerkAssignRejected)
+/// - 같은 프레임의 요청은 순서대로 처리(앞 요청의 결과가 뒤 요청의 검증에 반영)
+pub fn apply_perk_requests(
+    mut ev_req: EventReader<PerkAssignRequested>,
+    registry: Res<PerkRegistry>,
+    mut q_units: Query<PerkHolder>,
+    mut ev_applied: EventWriter<PerkAssignedApplied>,
+    mut ev_rejected: EventWriter<PerkAssignRejected>,
+) {
+    for req in ev_req.read() {
+        let reject = |reason: PerkRejectReason| PerkAssignRejected { unit: req.unit, perk: req.perk.clone(), reason };
+
+        let Ok((mut slots, mut propagation, mut on_hit, mut imbue, mut firing, mut traits_)) = q_units.get_mut(req.unit)
+        else {
+            ev_rejected.write(reject(PerkRejectReason::NoPerkSlots));
+            continue;
+        };
+        let level = match validate(&slots, &traits_, &propagation, &imbue, &registry, &req.perk) {
+            Ok(level) => level,
+            Err(reason) => {
+                ev_rejected.write(reject(reason));
+                continue;
+            }
+        };
+        let Some((slot, effect)) = registry.get(&req.perk).and_then(|d| Some((d.slot(), d.level(level)?.effect)))
+        else {
+            ev_rejected.write(reject(PerkRejectReason::UnknownPerk));
+            continue;
+        };
+
+        apply_effect(
+            effect,
+            PerkTargets {
+                propagation: &mut propagation,
+                on_hit: &mut on_hit,
+                imbue: &mut imbue,
+                firing: &mut firing,
+                traits_: &mut traits_,
+            },
+        );
+        slots.set(req.perk.clone(), level);
+        ev_applied.write(PerkAssignedApplied { unit: req.unit, perk: req.perk.clone(), slot, level });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::gameplay::perks::components::PerkId;
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<PerkAssignRequested>>();
+        world.init_resource::<Events<PerkAssignedApplied>>();
+        world.init_resource::<Events<PerkAssignRejected>>();
+        let table: PerkTable = ron::from_str(include_str!("../../../assets/rules/base.perks.ron")).unwrap();
+        world.insert_resource(PerkRegistry::from_table(&table));
+        world
+    }
+
+    fn request(world: &mut World, unit: Entity, perk: &str) -> (Vec<PerkAssignedApplied>, Vec<PerkAssignRejected>) {
+        world.send_event(PerkAssignRequested { unit, perk: PerkId::new(perk) });
+        world.run_system_once(apply_perk_requests).unwrap();
+        world.resource_mut::<Events<PerkAssignRequested>>().clear();
+        let applied = world.resource_mut::<Events<PerkAssignedApplied>>().drain().collect();
+        let rejected = world.resource_mut::<Events<PerkAssignRejected>>().drain().collect();
+        (applied, rejected)
+    }
+
+    #[test]
+    fn entity_without_perk_components_is_rejected() {
+        let mut world = world();
+        let bare = world.spawn_empty().id();
+        let (applied, rejected) = request(&mut world, bare, "cryo");
+        assert!(applied.is_empty());
+        assert_eq!(rejected[0].reason, PerkRejectReason::NoPerkSlots);
+    }
+
+    #[test]
+    fn valid_request_applies_effect_and_unknown_perk_is_rejected() {
+        let mut world = world();
+        let unit = world
+            .spawn((
+                PerkSlots::new(2),
+                Propagation::None,
+                OnHitEffects::default(),
+                ElementalImbue { element: None, cooldown_max: 0, cooldown: 0 },
+                FiringMods::default(),
+                ActionTraits::default(),
+            ))
+            .id();
+
+        let (applied, _) = request(&mut world, unit, "mortar");
+        assert_eq!(applied[0].level, 1);
+        assert!(world.get::<FiringMods>(unit).unwrap().lobbed);
+        assert_eq!(world.get::<PerkSlots>(unit).unwrap().level_of(&PerkId::new("mortar")), Some(1));
+
+        let (_, rejected) = request(&mut world, unit, "no_such_perk");
+        assert_eq!(rejected[0].reason, PerkRejectReason::UnknownPerk);
+    }
+}
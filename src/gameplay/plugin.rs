@@ -9,6 +9,10 @@ use crate::gameplay::tiles::prelude::{
 // 상호작용 & 유닛
 use crate::gameplay::interaction::InteractionPlugin;
 use crate::gameplay::units::UnitsPlugin;
+use crate::gameplay::commands::CommandsPlugin;
+use crate::gameplay::combat::CombatPlugin;
+use crate::gameplay::turns::TurnsPlugin;
+use crate::gameplay::perks::PerksPlugin;
 use crate::gameplay::units::assets::{enqueue_unit, PendingUnitLoads};
 
 pub struct GameplayPlugin;
@@ -20,6 +24,10 @@ impl Plugin for GameplayPlugin {
             .add_plugins(TilesPlugin)
             .add_plugins(InteractionPlugin)
             .add_plugins(UnitsPlugin)
+            .add_plugins(CommandsPlugin)
+            .add_plugins(CombatPlugin)
+            .add_plugins(TurnsPlugin)
+            .add_plugins(PerksPlugin)
             // 전투 씬 진입 시 셋업/스폰
             .add_systems(OnEnter(AppState::Battle), demo_setup_level_10x10)
             .add_systems(OnEnter(AppState::Battle), spawn_one_ally);
@@ -1,5 +1,6 @@
 // src/tiles/api.rs
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use super::{
     components::GridPos,
     events::{
@@ -11,19 +12,19 @@ use super::{
 /// 타일 모듈을 쓰는 쪽(입력/게임플레이/스폰)에서 호출하는 편의 API.
 /// 내부적으로 이벤트를 발행하고, 실제 적용은 tiles::systems가 처리합니다.
 #[derive(SystemParam)]
-pub struct TilesApi<'w, 's> {
+pub struct TilesApi<'w> {
     /// 맵/인덱스는 존재하지 않을 수도 있으므로 Option
     pub map:   Option<Res<'w, BaseTileMap>>,
     pub index: Option<Res<'w, GridIndex>>,
 
-    place_w:  EventWriter<'w, 's, GridPlace>,
-    remove_w: EventWriter<'w, 's, GridRemove>,
-    move_w:   EventWriter<'w, 's, GridMove>,
-    batch_w:  EventWriter<'w, 's, GridBatch>,
-    reinit_w: EventWriter<'w, 's, MapReinitialized>,
+    place_w:  EventWriter<'w, GridPlace>,
+    remove_w: EventWriter<'w, GridRemove>,
+    move_w:   EventWriter<'w, GridMove>,
+    batch_w:  EventWriter<'w, GridBatch>,
+    reinit_w: EventWriter<'w, MapReinitialized>,
 }
 
-impl<'w, 's> TilesApi<'w, 's> {
+impl<'w> TilesApi<'w> {
     // ===== 조회/유틸 =====
 
     /// 맵 크기 반환 (없으면 None)
@@ -52,19 +53,19 @@ impl<'w, 's> TilesApi<'w, 's> {
     /// 단건 배치 (검증은 시스템에서 수행)
     #[inline]
     pub fn place(&mut self, entity: Entity, at: GridPos) {
-        self.place_w.send(GridPlace { entity, at });
+        self.place_w.write(GridPlace { entity, at });
     }
 
     /// 단건 제거 (엔티티/좌표가 일치해야 제거되도록 시스템에서 검증)
     #[inline]
     pub fn remove(&mut self, entity: Entity, at: GridPos) {
-        self.remove_w.send(GridRemove { entity, at });
+        self.remove_w.write(GridRemove { entity, at });
     }
 
     /// 단건 이동
     #[inline]
     pub fn move_to(&mut self, entity: Entity, from: GridPos, to: GridPos) {
-        self.move_w.send(GridMove { entity, from, to });
+        self.move_w.write(GridMove { entity, from, to });
     }
 
     /// 자리 교환 (시스템이 각 엔티티의 현재 위치를 조회)
@@ -84,13 +85,13 @@ impl<'w, 's> TilesApi<'w, 's> {
     /// 단일 커맨드 전송
     #[inline]
     pub fn command(&mut self, cmd: GridCommand) {
-        self.batch_w.send(GridBatch::single(cmd));
+        self.batch_w.write(GridBatch::single(cmd));
     }
 
     /// 여러 커맨드 일괄 전송
     #[inline]
     pub fn batch<I: IntoIterator<Item = GridCommand>>(&mut self, ops: I) {
-        self.batch_w.send(GridBatch { ops: ops.into_iter().collect() });
+        self.batch_w.write(GridBatch { ops: ops.into_iter().collect() });
     }
 
     /// 대량 배치 (엔티티, 위치)
@@ -99,7 +100,7 @@ impl<'w, 's> TilesApi<'w, 's> {
             .into_iter()
             .map(|(entity, at)| GridCommand::place(entity, at))
             .collect();
-        self.batch_w.send(GridBatch { ops });
+        self.batch_w.write(GridBatch { ops });
     }
 
     /// 대량 제거 (엔티티/좌표 일치 기준)
@@ -109,7 +110,7 @@ impl<'w, 's> TilesApi<'w, 's> {
             .into_iter()
             .map(|(entity, at)| GridCommand::remove(entity, at))
             .collect();
-        self.batch_w.send(GridBatch { ops });
+        self.batch_w.write(GridBatch { ops });
     }
 
     /// 대량 이동 (엔티티, from, to)
@@ -119,14 +120,14 @@ impl<'w, 's> TilesApi<'w, 's> {
             .into_iter()
             .map(|(entity, from, to)| GridCommand::r#move(entity, from, to))
             .collect();
-        self.batch_w.send(GridBatch { ops });
+        self.batch_w.write(GridBatch { ops });
     }
 
     /// 여러 칸 비우기
     #[inline]
     pub fn clear_many<I: IntoIterator<Item = GridPos>>(&mut self, cells: I) {
         let ops = cells.into_iter().map(GridCommand::clear).collect();
-        self.batch_w.send(GridBatch { ops });
+        self.batch_w.write(GridBatch { ops });
     }
 
     // ===== 맵 리사이즈/재초기화 =====
@@ -134,12 +135,12 @@ impl<'w, 's> TilesApi<'w, 's> {
     /// 맵 리사이즈 + 전체 초기화(모두 비움)
     #[inline]
     pub fn resize_clear(&mut self, w: u32, h: u32) {
-        self.reinit_w.send(MapReinitialized { w, h, mode: ReindexMode::Clear });
+        self.reinit_w.write(MapReinitialized { w, h, mode: ReindexMode::Clear });
     }
 
     /// 기존 배치 보존 + 확장(축소는 거부)
     #[inline]
     pub fn resize_preserve_expand(&mut self, w: u32, h: u32) {
-        self.reinit_w.send(MapReinitialized { w, h, mode: ReindexMode::PreserveExpand });
+        self.reinit_w.write(MapReinitialized { w, h, mode: ReindexMode::PreserveExpand });
     }
 }
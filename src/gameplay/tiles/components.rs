@@ -37,6 +37,11 @@ impl GridPos {
     pub fn manhattan(self, other: GridPos) -> u32 {
         self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
     }
+    /// 대각 1칸 = 1 (8방향 거리)
+    #[inline]
+    pub fn chebyshev(self, other: GridPos) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
 }
 
 // ===== conversions =====
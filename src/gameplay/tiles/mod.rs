@@ -3,10 +3,14 @@ pub mod components;
 pub mod resources;
 pub mod systems;
 pub mod plugin; 
+pub mod pathfinding;
+pub mod api;
 
 
 pub mod prelude {   
     pub use super::components::{GridPos, TerrainKind};
     pub use super::resources::{BaseTileMap, GridIndex, GridError, TileConfig};
     pub use super::plugin::TilesPlugin;
+    pub use super::api::TilesApi;
+    pub use super::pathfinding::{Pathfinder, PathOptions, Neighborhood, TerrainCosts, Path, Reachable};
 }
\ No newline at end of file
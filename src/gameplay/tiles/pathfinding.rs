@@ -0,0 +1,560 @@
+// src/gameplay/tiles/pathfinding.rs
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+
+use super::{
+    components::{GridPos, TerrainKind},
+    resources::{BaseTileMap, GridIndex},
+};
+
+/// 4방향 이웃 오프셋 (상하좌우)
+const DIRS4: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+/// 8방향 이웃 오프셋 (상하좌우 → 대각 순서, 결정적 탐색 순서)
+const DIRS8: [(i32, i32); 8] = [
+    (1, 0), (-1, 0), (0, 1), (0, -1),
+    (1, 1), (-1, 1), (1, -1), (-1, -1),
+];
+
+/// 이웃 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Neighborhood {
+    /// 상하좌우
+    #[default]
+    Four,
+    /// 대각 포함 (벽 모서리 가로지르기는 금지)
+    Eight,
+}
+
+impl Neighborhood {
+    #[inline]
+    pub fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Neighborhood::Four => &DIRS4,
+            Neighborhood::Eight => &DIRS8,
+        }
+    }
+}
+
+/// 지형별 진입 비용 (None = 통과 불가)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerrainCosts(pub [Option<u32>; 5]);
+
+impl Default for TerrainCosts {
+    /// 보병 기준: is_passable_base면 1, 아니면 불가
+    fn default() -> Self {
+        let base = |k: TerrainKind| k.is_passable_base().then_some(1);
+        Self([
+            base(TerrainKind::Ground),
+            base(TerrainKind::Forest),
+            base(TerrainKind::Water),
+            base(TerrainKind::Wall),
+            base(TerrainKind::Road),
+        ])
+    }
+}
+
+impl TerrainCosts {
+    #[inline] pub fn get(&self, kind: TerrainKind) -> Option<u32> { self.0[kind as usize] }
+    #[inline] pub fn set(&mut self, kind: TerrainKind, cost: Option<u32>) { self.0[kind as usize] = cost; }
+    #[inline] pub fn with(mut self, kind: TerrainKind, cost: Option<u32>) -> Self { self.set(kind, cost); self }
+
+    /// 통과 가능한 지형 중 최소 비용(휴리스틱용, 모두 불가면 None)
+    #[inline]
+    pub fn min_cost(&self) -> Option<u32> {
+        self.0.iter().flatten().copied().min()
+    }
+}
+
+/// 경로 탐색 옵션
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PathOptions {
+    pub neighborhood: Neighborhood,
+    pub costs: TerrainCosts,
+    /// 점유 칸 통과 허용(ActionTraits::can_phase). 도착 칸은 여전히 비어 있어야 함
+    pub phase: bool,
+}
+
+impl PathOptions {
+    #[inline] pub fn with_neighborhood(mut self, n: Neighborhood) -> Self { self.neighborhood = n; self }
+    #[inline] pub fn with_costs(mut self, costs: TerrainCosts) -> Self { self.costs = costs; self }
+    #[inline] pub fn with_phase(mut self, phase: bool) -> Self { self.phase = phase; self }
+}
+
+/// 탐색 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    /// 출발점 제외, 도착점 포함
+    pub steps: Vec<GridPos>,
+    /// 지형 비용 합
+    pub cost: u32,
+}
+
+impl Path {
+    #[inline] pub fn len(&self) -> usize { self.steps.len() }
+    #[inline] pub fn is_empty(&self) -> bool { self.steps.is_empty() }
+    #[inline] pub fn last(&self) -> Option<GridPos> { self.steps.last().copied() }
+}
+
+/// 맵 경계 안의 이웃 좌표
+#[inline]
+pub(crate) fn neighbor(map: &BaseTileMap, p: GridPos, (dx, dy): (i32, i32)) -> Option<GridPos> {
+    let x = p.x as i32 + dx;
+    let y = p.y as i32 + dy;
+    if x < 0 || y < 0 { return None; }
+    let n = GridPos::new(x as u32, y as u32);
+    map.in_bounds_pos(n).then_some(n)
+}
+
+/// `p`에 진입하는 비용 (지형 불가 또는 점유(phase 아님)면 None)
+#[inline]
+pub(crate) fn step_cost(map: &BaseTileMap, index: &GridIndex, p: GridPos, opts: &PathOptions) -> Option<u32> {
+    let cost = opts.costs.get(map.terrain_at_pos(p))?;
+    (opts.phase || index.is_empty_pos(p)).then_some(cost)
+}
+
+/// 대각 이동 시 양옆 직교 칸 지형이 모두 통과 가능해야 함(벽 모서리 가로지르기 금지)
+#[inline]
+pub(crate) fn diagonal_open(map: &BaseTileMap, from: GridPos, (dx, dy): (i32, i32), opts: &PathOptions) -> bool {
+    if dx == 0 || dy == 0 { return true; }
+    let open = |d| neighbor(map, from, d).is_some_and(|n| opts.costs.get(map.terrain_at_pos(n)).is_some());
+    open((dx, 0)) && open((0, dy))
+}
+
+/// 도착 칸으로 쓸 수 있는지 (지형 통과 가능 + 비어 있음)
+#[inline]
+pub fn is_valid_goal(map: &BaseTileMap, index: &GridIndex, p: GridPos, opts: &PathOptions) -> bool {
+    map.in_bounds_pos(p)
+        && opts.costs.get(map.terrain_at_pos(p)).is_some()
+        && index.is_empty_pos(p)
+}
+
+/// 휴리스틱: 4방향=맨해튼, 8방향=체비셰프 (× 최소 지형 비용)
+#[inline]
+fn heuristic(a: GridPos, b: GridPos, opts: &PathOptions, min_cost: u32) -> u32 {
+    let d = match opts.neighborhood {
+        Neighborhood::Four => a.manhattan(b),
+        Neighborhood::Eight => a.x.abs_diff(b.x).max(a.y.abs_diff(b.y)),
+    };
+    d * min_cost
+}
+
+/// A* 최단 경로
+/// - 출발점 == 도착점이면 빈 경로(비용 0)
+/// - 도착 칸이 막혀 있거나 도달 불가면 None
+pub fn find_path(
+    map: &BaseTileMap,
+    index: &GridIndex,
+    from: GridPos,
+    to: GridPos,
+    opts: &PathOptions,
+) -> Option<Path> {
+    if !map.in_bounds_pos(from) || !map.in_bounds_pos(to) { return None; }
+    if from == to { return Some(Path { steps: Vec::new(), cost: 0 }); }
+    if !is_valid_goal(map, index, to, opts) { return None; }
+    let min_cost = opts.costs.min_cost()?;
+
+    let mut g: Vec<u32> = vec![u32::MAX; map.len()];
+    let mut prev: Vec<Option<GridPos>> = vec![None; map.len()];
+    // (f, h, g, idx) 최소 힙 → 같은 f면 목표에 가까운 쪽, 그다음 인덱스 순(결정적)
+    let mut open = BinaryHeap::new();
+
+    let si = map.idx_pos(from);
+    g[si] = 0;
+    let h0 = heuristic(from, to, opts, min_cost);
+    open.push(Reverse((h0, h0, 0u32, si)));
+
+    while let Some(Reverse((_, _, cg, ci))) = open.pop() {
+        if cg > g[ci] { continue; } // 이미 더 싸게 확장됨(낡은 힙 항목)
+        let cur = GridPos::new(ci as u32 % map.w, ci as u32 / map.w);
+        if cur == to { break; }
+        for &d in opts.neighborhood.offsets() {
+            let Some(n) = neighbor(map, cur, d) else { continue; };
+            if !diagonal_open(map, cur, d, opts) { continue; }
+            let Some(c) = step_cost(map, index, n, opts) else { continue; };
+            let ni = map.idx_pos(n);
+            let ng = g[ci].saturating_add(c);
+            if ng < g[ni] {
+                g[ni] = ng;
+                prev[ni] = Some(cur);
+                let h = heuristic(n, to, opts, min_cost);
+                open.push(Reverse((ng.saturating_add(h), h, ng, ni)));
+            }
+        }
+    }
+
+    let ti = map.idx_pos(to);
+    if g[ti] == u32::MAX { return None; }
+
+    // 도착점 → 출발점 역추적
+    let mut steps = vec![to];
+    let mut cur = to;
+    while let Some(p) = prev[map.idx_pos(cur)] {
+        if p == from { break; }
+        steps.push(p);
+        cur = p;
+    }
+    steps.reverse();
+    Some(Path { steps, cost: g[ti] })
+}
+
+/// 도달 가능 영역의 한 칸
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReachNode {
+    /// 출발점에서의 최소 누적 비용
+    pub cost: u32,
+    /// 최소 비용 경로상의 직전 칸 (출발점이면 None)
+    pub prev: Option<GridPos>,
+    /// 멈출 수 있는 칸인지 (phase로 지나가기만 하는 점유 칸은 false)
+    pub can_stop: bool,
+}
+
+/// 예산 내 도달 가능 영역 (Dijkstra 결과)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reachable {
+    pub origin: GridPos,
+    pub budget: u32,
+    nodes: HashMap<GridPos, ReachNode>,
+}
+
+impl Reachable {
+    /// 멈출 수 있는(=이동 목적지로 유효한) 칸인지. 출발점은 제외
+    #[inline]
+    pub fn contains(&self, p: GridPos) -> bool {
+        p != self.origin && self.nodes.get(&p).is_some_and(|n| n.can_stop)
+    }
+
+    /// 탐색된 칸의 정보 (지나가기만 하는 칸 포함)
+    #[inline]
+    pub fn node(&self, p: GridPos) -> Option<&ReachNode> { self.nodes.get(&p) }
+
+    /// 목적지까지의 비용 (목적지로 유효하지 않으면 None)
+    #[inline]
+    pub fn cost(&self, p: GridPos) -> Option<u32> {
+        self.contains(p).then(|| self.nodes[&p].cost)
+    }
+
+    /// 이동 목적지로 유효한 칸들 (정렬: y → x)
+    pub fn tiles(&self) -> Vec<GridPos> {
+        let mut v: Vec<GridPos> = self.nodes.keys().copied().filter(|&p| self.contains(p)).collect();
+        v.sort_by_key(|p| (p.y, p.x));
+        v
+    }
+
+    /// 목적지까지의 경로 복원 (목적지로 유효하지 않으면 None)
+    pub fn path_to(&self, to: GridPos) -> Option<Path> {
+        let cost = self.cost(to)?;
+        let mut steps = vec![to];
+        let mut cur = to;
+        while let Some(p) = self.nodes.get(&cur).and_then(|n| n.prev) {
+            if p == self.origin { break; }
+            steps.push(p);
+            cur = p;
+        }
+        steps.reverse();
+        Some(Path { steps, cost })
+    }
+}
+
+/// Dijkstra 플러드 필: `budget` 이내로 도달 가능한 모든 칸
+/// - 지형 비용/통과 불가는 opts.costs 기준
+/// - 점유 칸은 phase일 때만 통과(멈출 수는 없음)
+pub fn reachable(
+    map: &BaseTileMap,
+    index: &GridIndex,
+    from: GridPos,
+    budget: u32,
+    opts: &PathOptions,
+) -> Reachable {
+    let mut nodes = HashMap::new();
+    if !map.in_bounds_pos(from) {
+        return Reachable { origin: from, budget, nodes };
+    }
+
+    let mut best: Vec<u32> = vec![u32::MAX; map.len()];
+    let mut open = BinaryHeap::new();
+    let si = map.idx_pos(from);
+    best[si] = 0;
+    nodes.insert(from, ReachNode { cost: 0, prev: None, can_stop: true });
+    open.push(Reverse((0u32, si)));
+
+    while let Some(Reverse((cost, ci))) = open.pop() {
+        if cost > best[ci] { continue; } // 이미 더 싸게 확정됨
+        let cur = GridPos::new(ci as u32 % map.w, ci as u32 / map.w);
+        for &d in opts.neighborhood.offsets() {
+            let Some(n) = neighbor(map, cur, d) else { continue; };
+            if !diagonal_open(map, cur, d, opts) { continue; }
+            let Some(c) = step_cost(map, index, n, opts) else { continue; };
+            let nc = cost.saturating_add(c);
+            let ni = map.idx_pos(n);
+            if nc > budget || nc >= best[ni] { continue; }
+            best[ni] = nc;
+            nodes.insert(n, ReachNode { cost: nc, prev: Some(cur), can_stop: index.is_empty_pos(n) });
+            open.push(Reverse((nc, ni)));
+        }
+    }
+
+    Reachable { origin: from, budget, nodes }
+}
+
+/// 경로 캐시 — GridIndex::version이 바뀌면 통째로 무효화
+/// (hover 미리보기처럼 같은 질의가 반복될 때 비용 절감)
+#[derive(Resource, Debug, Default)]
+pub struct PathCache {
+    version: Option<u32>,
+    entries: HashMap<(GridPos, GridPos, PathOptions), Option<Path>>,
+    reach: HashMap<(GridPos, u32, PathOptions), Reachable>,
+}
+
+impl PathCache {
+    /// 캐시 항목 상한(넘으면 비움)
+    pub const MAX_ENTRIES: usize = 4096;
+
+    /// 전체 무효화 (맵 교체 등)
+    pub fn clear(&mut self) {
+        self.version = None;
+        self.entries.clear();
+        self.reach.clear();
+    }
+
+    /// 인덱스 버전이 바뀌었으면 비우기
+    fn sync_version(&mut self, index: &GridIndex) {
+        if self.version != Some(index.version) {
+            self.entries.clear();
+            self.reach.clear();
+            self.version = Some(index.version);
+        }
+    }
+
+    /// 캐시 조회 → 없으면 A* 계산 후 저장
+    pub fn find_path(
+        &mut self,
+        map: &BaseTileMap,
+        index: &GridIndex,
+        from: GridPos,
+        to: GridPos,
+        opts: &PathOptions,
+    ) -> Option<Path> {
+        self.sync_version(index);
+        let key = (from, to, *opts);
+        if let Some(hit) = self.entries.get(&key) {
+            return hit.clone();
+        }
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.entries.clear();
+        }
+        let result = find_path(map, index, from, to, opts);
+        self.entries.insert(key, result.clone());
+        result
+    }
+
+    /// 캐시 조회 → 없으면 Dijkstra 플러드 필 계산 후 저장
+    pub fn reachable(
+        &mut self,
+        map: &BaseTileMap,
+        index: &GridIndex,
+        from: GridPos,
+        budget: u32,
+        opts: &PathOptions,
+    ) -> Reachable {
+        self.sync_version(index);
+        let key = (from, budget, *opts);
+        if let Some(hit) = self.reach.get(&key) {
+            return hit.clone();
+        }
+        if self.reach.len() >= Self::MAX_ENTRIES {
+            self.reach.clear();
+        }
+        let result = reachable(map, index, from, budget, opts);
+        self.reach.insert(key, result.clone());
+        result
+    }
+}
+
+/// 이동 실행/AI/오버레이가 공유하는 경로 탐색 SystemParam
+#[derive(SystemParam)]
+pub struct Pathfinder<'w> {
+    pub map:   Option<Res<'w, BaseTileMap>>,
+    pub index: Option<Res<'w, GridIndex>>,
+    cache: ResMut<'w, PathCache>,
+}
+
+impl<'w> Pathfinder<'w> {
+    /// 맵/인덱스가 없으면 None
+    pub fn find_path(&mut self, from: GridPos, to: GridPos, opts: &PathOptions) -> Option<Path> {
+        let (map, index) = (self.map.as_deref()?, self.index.as_deref()?);
+        self.cache.find_path(map, index, from, to, opts)
+    }
+
+    /// 예산 내 도달 가능 영역 (맵/인덱스가 없으면 None)
+    pub fn reachable(&mut self, from: GridPos, budget: u32, opts: &PathOptions) -> Option<Reachable> {
+        let (map, index) = (self.map.as_deref()?, self.index.as_deref()?);
+        Some(self.cache.reachable(map, index, from, budget, opts))
+    }
+
+    /// 도착 칸으로 쓸 수 있는지 (맵/인덱스가 없으면 false)
+    pub fn is_valid_goal(&self, p: GridPos, opts: &PathOptions) -> bool {
+        match (self.map.as_deref(), self.index.as_deref()) {
+            (Some(map), Some(index)) => is_valid_goal(map, index, p, opts),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 행 문자열 → 맵/인덱스 ('.' 평지, 'f' 숲, '~' 물, '#' 벽, '=' 도로, 'U' 유닛이 선 평지)
+    fn board(rows: &[&str]) -> (BaseTileMap, GridIndex) {
+        let (w, h) = (rows[0].len() as u32, rows.len() as u32);
+        let mut terrain = Vec::new();
+        let mut index = GridIndex::new(w, h);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                terrain.push(match c {
+                    'f' => TerrainKind::Forest,
+                    '~' => TerrainKind::Water,
+                    '#' => TerrainKind::Wall,
+                    '=' => TerrainKind::Road,
+                    _ => TerrainKind::Ground,
+                });
+                if c == 'U' {
+                    index.place(x as u32, y as u32, Entity::from_raw(y as u32 * w + x as u32)).unwrap();
+                }
+            }
+        }
+        (BaseTileMap::new(w, h, terrain), index)
+    }
+
+    fn p(x: u32, y: u32) -> GridPos { GridPos::new(x, y) }
+
+    fn eight() -> PathOptions { PathOptions::default().with_neighborhood(Neighborhood::Eight) }
+
+    #[test]
+    fn four_dir_walks_orthogonally_eight_dir_cuts_diagonally() {
+        let (map, index) = board(&["...", "...", "..."]);
+
+        let four = find_path(&map, &index, p(0, 0), p(2, 2), &PathOptions::default()).unwrap();
+        assert_eq!(four.cost, 4);
+        assert!(four.steps.windows(2).all(|s| s[0].manhattan(s[1]) == 1));
+
+        let diag = find_path(&map, &index, p(0, 0), p(2, 2), &eight()).unwrap();
+        assert_eq!(diag.steps, [p(1, 1), p(2, 2)]);
+        assert_eq!(diag.cost, 2);
+    }
+
+    #[test]
+    fn diagonal_cannot_cut_wall_corner() {
+        // (1,0)이 벽 → (0,0)→(1,1) 대각 금지, 돌아서 2칸
+        let (map, index) = board(&[".#", ".."]);
+        let path = find_path(&map, &index, p(0, 0), p(1, 1), &eight()).unwrap();
+        assert_eq!(path.steps, [p(0, 1), p(1, 1)]);
+
+        // 양옆이 모두 벽이면 경로 없음
+        let (map, index) = board(&[".#", "#."]);
+        assert_eq!(find_path(&map, &index, p(0, 0), p(1, 1), &eight()), None);
+    }
+
+    #[test]
+    fn blocked_occupied_or_out_of_bounds_goal_is_none() {
+        let (map, index) = board(&["..#", "..U"]);
+        let opts = PathOptions::default();
+        assert_eq!(find_path(&map, &index, p(0, 0), p(2, 0), &opts), None); // 벽
+        assert_eq!(find_path(&map, &index, p(0, 0), p(2, 1), &opts), None); // 점유
+        assert_eq!(find_path(&map, &index, p(0, 0), p(3, 0), &opts), None); // 맵 밖
+        assert_eq!(find_path(&map, &index, p(0, 0), p(0, 0), &opts).map(|p| p.cost), Some(0));
+    }
+
+    #[test]
+    fn cheaper_route_found_later_replaces_stale_entry() {
+        // (3,1)은 숲길로 먼저 발견되고(비용 큼) 나중에 도로로 더 싸게 갱신됨
+        // — 낡은 힙 항목을 건너뛰어도 모든 칸에서 Dijkstra와 같은 최소 비용이어야 함
+        let (map, index) = board(&[
+            "=====",
+            ".fff.",
+            ".#.#.",
+            ".....",
+        ]);
+        let opts = PathOptions::default().with_costs(TerrainCosts::default().with(TerrainKind::Forest, Some(5)));
+        let reach = reachable(&map, &index, p(0, 1), u32::MAX, &opts);
+        for y in 0..map.h {
+            for x in 0..map.w {
+                let to = p(x, y);
+                let astar = find_path(&map, &index, p(0, 1), to, &opts).filter(|path| !path.is_empty());
+                assert_eq!(astar.map(|path| path.cost), reach.cost(to), "{to:?}");
+            }
+        }
+        assert_eq!(find_path(&map, &index, p(0, 1), p(4, 1), &opts).unwrap().cost, 6);
+    }
+
+    #[test]
+    fn cache_is_invalidated_when_index_version_changes() {
+        let (map, mut index) = board(&["...", "...", "..."]);
+        let mut cache = PathCache::default();
+        let opts = PathOptions::default();
+        assert_eq!(cache.find_path(&map, &index, p(0, 0), p(2, 0), &opts).unwrap().cost, 2);
+
+        // 버전이 같으면 캐시 결과 그대로 (지형은 버전에 안 잡힘)
+        let mut walled = BaseTileMap::filled(3, 3, TerrainKind::Ground);
+        walled.terrain[1] = TerrainKind::Wall;
+        assert_eq!(cache.find_path(&walled, &index, p(0, 0), p(2, 0), &opts).unwrap().cost, 2);
+
+        // 유닛 배치로 버전이 바뀌면 다시 계산
+        index.place(1, 0, Entity::from_raw(1)).unwrap();
+        assert_eq!(cache.find_path(&map, &index, p(0, 0), p(2, 0), &opts).unwrap().cost, 4);
+        assert_eq!(cache.reachable(&map, &index, p(0, 0), 1, &opts).tiles(), [p(0, 1)]);
+    }
+
+    #[test]
+    fn costly_tile_cuts_reach() {
+        let (map, index) = board(&[".f..."]);
+        let plain = reachable(&map, &index, p(0, 0), 3, &PathOptions::default());
+        assert_eq!(plain.tiles(), [p(1, 0), p(2, 0), p(3, 0)]);
+
+        let opts = PathOptions::default().with_costs(TerrainCosts::default().with(TerrainKind::Forest, Some(2)));
+        let reach = reachable(&map, &index, p(0, 0), 3, &opts);
+        assert_eq!(reach.tiles(), [p(1, 0), p(2, 0)]);
+        assert_eq!(reach.cost(p(2, 0)), Some(3));
+    }
+
+    #[test]
+    fn impassable_terrain_is_excluded() {
+        let (map, index) = board(&[".~.", "...", "#.."]);
+        let reach = reachable(&map, &index, p(0, 0), 10, &PathOptions::default());
+        assert!(!reach.contains(p(1, 0)) && reach.node(p(1, 0)).is_none());
+        assert!(!reach.contains(p(0, 2)));
+        assert_eq!(reach.cost(p(2, 0)), Some(4)); // 물을 돌아감
+
+        // 정책에서 물을 허용하면 건널 수 있음
+        let swim = PathOptions::default().with_costs(TerrainCosts::default().with(TerrainKind::Water, Some(1)));
+        assert_eq!(reachable(&map, &index, p(0, 0), 10, &swim).cost(p(2, 0)), Some(2));
+    }
+
+    #[test]
+    fn budget_boundary_is_inclusive() {
+        let (map, index) = board(&["....."]);
+        let reach = reachable(&map, &index, p(0, 0), 2, &PathOptions::default());
+        assert!(reach.contains(p(2, 0)));
+        assert!(!reach.contains(p(3, 0)));
+        assert_eq!(reachable(&map, &index, p(0, 0), 0, &PathOptions::default()).tiles(), []);
+    }
+
+    #[test]
+    fn phase_passes_through_occupied_tile_but_cannot_stop_on_it() {
+        // 1칸 폭 복도 한가운데 유닛
+        let (map, index) = board(&[".U.."]);
+        let walk = reachable(&map, &index, p(0, 0), 3, &PathOptions::default());
+        assert_eq!(walk.tiles(), []);
+
+        let phase = PathOptions::default().with_phase(true);
+        let reach = reachable(&map, &index, p(0, 0), 3, &phase);
+        assert_eq!(reach.tiles(), [p(2, 0), p(3, 0)]);
+        assert!(!reach.contains(p(1, 0)));
+        assert_eq!(reach.node(p(1, 0)).map(|n| (n.cost, n.can_stop)), Some((1, false)));
+        assert_eq!(reach.path_to(p(3, 0)).map(|path| path.steps), Some(vec![p(1, 0), p(2, 0), p(3, 0)]));
+        assert_eq!(reach.path_to(p(1, 0)), None);
+    }
+}
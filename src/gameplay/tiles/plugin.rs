@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use super::{
     events::{GridPlace, GridRemove, GridMove, GridBatch, MapReinitialized},
-    systems::{handle_map_reinitialized, apply_grid_events, sync_gridpos_transforms},
+    systems::{handle_map_reinitialized, invalidate_path_cache, apply_grid_events, sync_gridpos_transforms},
+    pathfinding::PathCache,
 };
 
 /// 시스템 실행 순서 정의
@@ -19,6 +20,8 @@ impl Plugin for TilesPlugin {
     fn build(&self, app: &mut App) {
         
         app
+            // 경로 캐시
+            .init_resource::<PathCache>()
             // 이벤트 등록
             .add_event::<GridPlace>()
             .add_event::<GridRemove>()
@@ -28,7 +31,7 @@ impl Plugin for TilesPlugin {
             // 순서: Reindex → ApplyCommands
             .configure_sets(PreUpdate, (TilesSet::Reindex, TilesSet::ApplyCommands).chain())
             // PreUpdate: 먼저 맵 재인덱싱
-            .add_systems(PreUpdate, handle_map_reinitialized.in_set(TilesSet::Reindex))
+            .add_systems(PreUpdate, (handle_map_reinitialized, invalidate_path_cache).in_set(TilesSet::Reindex))
             // PreUpdate: 그 다음 커맨드 적용(인덱스/컴포넌트 반영)
             .add_systems(PreUpdate, apply_grid_events.in_set(TilesSet::ApplyCommands))
             // Update: Transform 동기화(렌더 전 위치 갱신)
@@ -3,6 +3,7 @@ use super::{
     components::GridPos,
     resources::{BaseTileMap, GridIndex, GridError, TileConfig},
     events::{GridPlace, GridRemove, GridMove, GridBatch, GridCommand, MapReinitialized, ReindexMode},
+    pathfinding::PathCache,
 };
 
 /// 1) 맵 재초기화 이벤트 처리: GridIndex 크기/내용 조정
@@ -24,6 +25,19 @@ pub fn handle_map_reinitialized(
     }
 }
 
+/// 1-1) 맵 교체/리사이즈 시 경로 캐시 무효화
+/// (GridIndex::version 변화는 캐시가 스스로 감지)
+pub fn invalidate_path_cache(
+    map: Option<Res<BaseTileMap>>,
+    mut ev: EventReader<MapReinitialized>,
+    mut cache: ResMut<PathCache>,
+) {
+    let map_changed = map.is_some_and(|m| m.is_changed());
+    if map_changed || ev.read().next().is_some() {
+        cache.clear();
+    }
+}
+
 /// 2) 단일 이벤트 + 배치 커맨드 적용 → GridIndex & GridPos 컴포넌트 동기화
 pub fn apply_grid_events(
     mut commands: Commands,
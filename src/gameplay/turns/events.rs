@@ -0,0 +1,17 @@
+// src/gameplay/turns/events.rs
+use bevy::prelude::*;
+use crate::gameplay::units::components::TeamId;
+
+/// 팀 페이즈 시작(예산 리셋/쿨다운 감소 반영 후)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnStarted {
+    pub turn: u32,
+    pub team: TeamId,
+}
+
+/// 팀 페이즈 종료
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnEnded {
+    pub turn: u32,
+    pub team: TeamId,
+}
@@ -0,0 +1,52 @@
+// src/gameplay/turns/guard.rs
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+
+use crate::gameplay::units::components::TeamId;
+use super::resources::TurnState;
+
+/// 명령 실행기용 가드: 활성 팀 유닛만 행동 가능
+/// (TurnState가 아직 없으면 모두 허용)
+#[derive(SystemParam)]
+pub struct TurnGuard<'w, 's> {
+    turn: Option<Res<'w, TurnState>>,
+    q_team: Query<'w, 's, &'static TeamId>,
+}
+
+impl<'w, 's> TurnGuard<'w, 's> {
+    /// 이 유닛이 지금 행동할 수 있는지
+    pub fn can_act(&self, unit: Entity) -> bool {
+        let Some(turn) = self.turn.as_deref() else { return true; };
+        self.q_team.get(unit).is_ok_and(|&team| turn.is_active(team))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::gameplay::turns::resources::TurnPhase;
+
+    #[test]
+    fn only_units_of_the_active_team_can_act() {
+        let mut world = World::new();
+        let ally = world.spawn(TeamId(0)).id();
+        let enemy = world.spawn(TeamId(1)).id();
+        let can_act = |world: &mut World, unit: Entity| {
+            world.run_system_once(move |guard: TurnGuard| guard.can_act(unit)).unwrap()
+        };
+
+        // TurnState 전에는 모두 허용
+        assert!(can_act(&mut world, enemy));
+
+        let mut turn = TurnState::new(vec![TeamId(0), TeamId(1)]).unwrap();
+        turn.phase = TurnPhase::Active;
+        world.insert_resource(turn);
+        assert!(can_act(&mut world, ally));
+        assert!(!can_act(&mut world, enemy));
+
+        world.resource_mut::<TurnState>().advance();
+        assert!(!can_act(&mut world, ally)); // 다음 페이즈 Begin 처리 전
+    }
+}
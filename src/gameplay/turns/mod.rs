@@ -0,0 +1,11 @@
+// src/gameplay/turns/mod.rs
+pub mod resources;
+pub mod events;
+pub mod guard;
+pub mod systems;
+pub mod plugin;
+
+pub use plugin::{TurnsPlugin, TurnSet};
+pub use resources::{TurnState, TurnPhase, TurnConfig};
+pub use events::{TurnStarted, TurnEnded};
+pub use guard::TurnGuard;
@@ -0,0 +1,55 @@
+// src/gameplay/turns/plugin.rs
+use bevy::prelude::*;
+use bevy::ecs::schedule::common_conditions::resource_exists;
+
+use crate::app::state::AppState;
+use crate::gameplay::combat::CombatSet;
+use crate::gameplay::commands::CommandsSet;
+use crate::gameplay::units::resources::Teams;
+
+use super::{
+    events::{TurnEnded, TurnStarted},
+    resources::{TurnConfig, TurnState},
+    systems::{begin_team_phase, end_team_phase, setup_turn_state, teardown_turn_state},
+};
+
+/// 턴 진행 순서 정의
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TurnSet {
+    /// 팀 페이즈 시작 처리 (명령 실행 전)
+    Begin,
+    /// 턴 종료 처리 (전투 해석 후)
+    End,
+}
+
+/// 팀 페이즈 기반 턴 루프
+pub struct TurnsPlugin;
+
+impl Plugin for TurnsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Teams>()
+            .init_resource::<TurnConfig>()
+            .add_event::<TurnStarted>()
+            .add_event::<TurnEnded>()
+            // 순서: Begin → (명령 실행 → 전투 해석) → End
+            .configure_sets(Update, TurnSet::Begin.before(CommandsSet::Execute))
+            .configure_sets(Update, TurnSet::End.after(CombatSet::Resolve))
+            .add_systems(OnEnter(AppState::Battle), setup_turn_state)
+            .add_systems(
+                Update,
+                begin_team_phase
+                    .in_set(TurnSet::Begin)
+                    .run_if(in_state(AppState::Battle))
+                    .run_if(resource_exists::<TurnState>),
+            )
+            .add_systems(
+                Update,
+                end_team_phase
+                    .in_set(TurnSet::End)
+                    .run_if(in_state(AppState::Battle))
+                    .run_if(resource_exists::<TurnState>),
+            )
+            .add_systems(OnExit(AppState::Battle), teardown_turn_state);
+    }
+}
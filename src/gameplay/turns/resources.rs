@@ -0,0 +1,82 @@
+// src/gameplay/turns/resources.rs
+use bevy::prelude::*;
+use crate::gameplay::units::components::TeamId;
+
+/// 팀 페이즈 진행 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TurnPhase {
+    /// 페이즈 시작 처리(예산 리셋/쿨다운 감소) 대기
+    Begin,
+    /// 활성 팀이 행동 중
+    Active,
+}
+
+/// 턴 진행 상태 — 단일 진실(리소스)
+/// - order 순서대로 팀 페이즈가 돌고, 한 바퀴가 끝나면 turn + 1
+#[derive(Resource, Debug, Clone)]
+pub struct TurnState {
+    /// 1부터 시작
+    pub turn: u32,
+    pub order: Vec<TeamId>,
+    pub active_idx: usize,
+    pub phase: TurnPhase,
+}
+
+impl TurnState {
+    /// 팀 순서가 비어 있으면 None
+    pub fn new(order: Vec<TeamId>) -> Option<Self> {
+        (!order.is_empty()).then_some(Self { turn: 1, order, active_idx: 0, phase: TurnPhase::Begin })
+    }
+
+    #[inline] pub fn active_team(&self) -> TeamId { self.order[self.active_idx] }
+    #[inline] pub fn is_active(&self, team: TeamId) -> bool { self.phase == TurnPhase::Active && self.active_team() == team }
+
+    /// 다음 팀 페이즈로 (한 바퀴 돌면 turn 증가). 새 페이즈는 Begin
+    pub fn advance(&mut self) {
+        self.active_idx += 1;
+        if self.active_idx >= self.order.len() {
+            self.active_idx = 0;
+            self.turn += 1;
+        }
+        self.phase = TurnPhase::Begin;
+    }
+}
+
+/// 턴 진행 설정
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct TurnConfig {
+    /// 플레이어 팀이 아닌 페이즈를 즉시 종료 (기본 꺼짐 — 켜면 적 페이즈가 실행되지 않고 넘어감)
+    pub auto_end_non_player: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_rotate_in_order_and_turn_increments_on_wrap() {
+        let mut turn = TurnState::new(vec![TeamId(0), TeamId(1), TeamId(2)]).unwrap();
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            seen.push((turn.turn, turn.active_team()));
+            assert_eq!(turn.phase, TurnPhase::Begin);
+            turn.phase = TurnPhase::Active;
+            turn.advance();
+        }
+        assert_eq!(seen, [(1, TeamId(0)), (1, TeamId(1)), (1, TeamId(2)), (2, TeamId(0))]);
+    }
+
+    #[test]
+    fn only_active_phase_of_active_team_counts() {
+        let mut turn = TurnState::new(vec![TeamId(0), TeamId(1)]).unwrap();
+        assert!(!turn.is_active(TeamId(0))); // Begin 처리 전
+        turn.phase = TurnPhase::Active;
+        assert!(turn.is_active(TeamId(0)));
+        assert!(!turn.is_active(TeamId(1)));
+    }
+
+    #[test]
+    fn empty_order_is_rejected() {
+        assert!(TurnState::new(Vec::new()).is_none());
+    }
+}
@@ -0,0 +1,162 @@
+// src/gameplay/turns/systems.rs
+use bevy::prelude::*;
+
+use crate::gameplay::interaction::CommandRequested;
+use crate::gameplay::units::{
+    components::{ActionBudget, ActionTraits, ElementalImbue, Magazine, MoveBudget, TeamId, Unit},
+    resources::{PlayerTeamId, Teams},
+};
+
+use super::{
+    events::{TurnEnded, TurnStarted},
+    resources::{TurnConfig, TurnPhase, TurnState},
+};
+
+/// Battle 진입 시 턴 상태 초기화 (Teams 순서: 아군 → 적군)
+pub fn setup_turn_state(mut commands: Commands, teams: Res<Teams>) {
+    match TurnState::new(vec![teams.ally, teams.enemy]) {
+        Some(state) => commands.insert_resource(state),
+        None => warn!("turn order is empty; turn loop disabled"),
+    }
+}
+
+/// Battle 종료 시 턴 상태 제거
+pub fn teardown_turn_state(mut commands: Commands) {
+    commands.remove_resource::<TurnState>();
+}
+
+/// 페이즈 시작 시 리셋되는 유닛 상태
+type PhaseReset = (
+    &'static TeamId,
+    &'static mut MoveBudget,
+    &'static mut ActionBudget,
+    Option<&'static mut ElementalImbue>,
+    Option<&'static mut Magazine>,
+    Option<&'static ActionTraits>,
+);
+
+/// 팀 페이즈 시작: 활성 팀 유닛의 Move/AP 예산 리셋 + 속성 쿨다운 1 감소 + 자동 재장전
+pub fn begin_team_phase(
+    mut turn: ResMut<TurnState>,
+    mut q_units: Query<PhaseReset, With<Unit>>,
+    mut ev_started: EventWriter<TurnStarted>,
+) {
+    if turn.phase != TurnPhase::Begin { return; }
+
+    let team = turn.active_team();
+    for (&t, mut mv, mut ap, imbue, magazine, traits_) in &mut q_units {
+        if t != team { continue; }
+        mv.reset();
+        ap.reset();
+        if let Some(mut imbue) = imbue {
+            imbue.cooldown = imbue.cooldown.saturating_sub(1);
+        }
+        if let Some(mut magazine) = magazine.filter(|_| traits_.is_some_and(|t| t.auto_reload)) {
+            magazine.refill();
+        }
+    }
+
+    turn.phase = TurnPhase::Active;
+    ev_started.write(TurnStarted { turn: turn.turn, team });
+}
+
+/// CommandRequested::EndTurn (또는 플레이어 팀이 아닌 페이즈 자동 종료) → 다음 팀 페이즈
+pub fn end_team_phase(
+    mut ev_cmd: EventReader<CommandRequested>,
+    mut turn: ResMut<TurnState>,
+    cfg: Res<TurnConfig>,
+    player: Res<PlayerTeamId>,
+    mut ev_ended: EventWriter<TurnEnded>,
+) {
+    let requested = ev_cmd.read().any(|c| matches!(c, CommandRequested::EndTurn));
+    if turn.phase != TurnPhase::Active { return; }
+
+    let team = turn.active_team();
+    let auto_end = cfg.auto_end_non_player && team.0 != player.0;
+    if !(requested || auto_end) { return; }
+
+    ev_ended.write(TurnEnded { turn: turn.turn, team });
+    turn.advance();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<TurnStarted>>();
+        world.init_resource::<Events<TurnEnded>>();
+        world.init_resource::<Events<CommandRequested>>();
+        world.init_resource::<TurnConfig>();
+        world.init_resource::<PlayerTeamId>();
+        world.insert_resource(TurnState::new(vec![TeamId(0), TeamId(1)]).unwrap());
+        world
+    }
+
+    /// 예산을 다 쓰고 쿨다운이 2 남은 유닛
+    fn spent_unit(world: &mut World, team: u8) -> Entity {
+        let mut mv = MoveBudget::new(4);
+        mv.current = 0;
+        let mut ap = ActionBudget::new(2);
+        ap.current = 0;
+        ap.attacked = true;
+        let imbue = ElementalImbue { element: None, cooldown_max: 3, cooldown: 2 };
+        world.spawn((Unit, TeamId(team), mv, ap, imbue)).id()
+    }
+
+    #[test]
+    fn phase_start_resets_budgets_and_ticks_cooldown_of_active_team_only() {
+        let mut world = world();
+        let ally = spent_unit(&mut world, 0);
+        let enemy = spent_unit(&mut world, 1);
+
+        world.run_system_once(begin_team_phase).unwrap();
+
+        let e = world.entity(ally);
+        assert_eq!(e.get::<MoveBudget>().unwrap().current, 4);
+        let ap = e.get::<ActionBudget>().unwrap();
+        assert_eq!((ap.current, ap.attacked), (2, false));
+        assert_eq!(e.get::<ElementalImbue>().unwrap().cooldown, 1);
+
+        let e = world.entity(enemy);
+        assert_eq!(e.get::<MoveBudget>().unwrap().current, 0);
+        assert_eq!(e.get::<ElementalImbue>().unwrap().cooldown, 2);
+
+        assert_eq!(world.resource::<TurnState>().phase, TurnPhase::Active);
+        let started: Vec<_> = world.resource_mut::<Events<TurnStarted>>().drain().collect();
+        assert_eq!(started, [TurnStarted { turn: 1, team: TeamId(0) }]);
+    }
+
+    #[test]
+    fn end_turn_advances_and_enemy_phase_waits_by_default() {
+        let mut world = world();
+        world.run_system_once(begin_team_phase).unwrap();
+        world.send_event(CommandRequested::EndTurn);
+        world.run_system_once(end_team_phase).unwrap();
+        assert_eq!(world.resource::<TurnState>().active_team(), TeamId(1));
+        world.resource_mut::<Events<CommandRequested>>().clear();
+
+        // 적 페이즈: 자동 종료 꺼짐(기본) → 명령 없이는 그대로
+        world.run_system_once(begin_team_phase).unwrap();
+        world.run_system_once(end_team_phase).unwrap();
+        let turn = world.resource::<TurnState>();
+        assert_eq!((turn.active_team(), turn.phase), (TeamId(1), TurnPhase::Active));
+    }
+
+    #[test]
+    fn phase_start_refills_magazine_only_with_auto_reload() {
+        let mut world = world();
+        let empty = Magazine { current: 0, ..Magazine::new(3, 1) };
+        let auto = ActionTraits { auto_reload: true, ..ActionTraits::default() };
+        let reloader = world.spawn((Unit, TeamId(0), MoveBudget::new(4), ActionBudget::new(2), empty, auto)).id();
+        let manual = world.spawn((Unit, TeamId(0), MoveBudget::new(4), ActionBudget::new(2), empty)).id();
+
+        world.run_system_once(begin_team_phase).unwrap();
+
+        assert_eq!(world.get::<Magazine>(reloader).unwrap().current, 3);
+        assert_eq!(world.get::<Magazine>(manual).unwrap().current, 0);
+    }
+}
@@ -1,16 +1,28 @@
 // src/gameplay/units/components.rs
 use bevy::prelude::*;
+use crate::gameplay::tiles::components::GridPos;
+use crate::gameplay::perks::components::PerkSlots;
 
 /// 모든 유닛에 붙는 마커
 #[derive(Component, Debug)]
 pub struct Unit;
 
+/// 사망 처리 중(그리드 제거 대기) — Unit 마커는 이미 떨어진 상태
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Dead;
+
+/// 시체 마커 (DeathConfig::leave_corpse일 때 남음)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Corpse {
+    pub at: Option<GridPos>,
+}
+
 /// 진영/팀 식별자 (예: 아군=0, 적군=1)
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TeamId(pub u8);
 
 /// 체력/쉴드 (코어 스탯)
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Stats {
     pub hp: i32,
     pub max_hp: i32,
@@ -47,15 +59,31 @@ impl MoveBudget {
 pub struct ActionBudget {
     pub current: u32,
     pub per_turn: u32,
+    /// 이번 페이즈에 공격했는지 (ActionTraits::can_move_after_attack 판정용)
+    pub attacked: bool,
 }
 impl ActionBudget {
-    pub fn new(per_turn: u32) -> Self { Self { current: per_turn, per_turn } }
-    #[inline] pub fn reset(&mut self) { self.current = self.per_turn; }
+    pub fn new(per_turn: u32) -> Self { Self { current: per_turn, per_turn, attacked: false } }
+    #[inline] pub fn reset(&mut self) { self.current = self.per_turn; self.attacked = false; }
     #[inline] pub fn try_spend(&mut self, n: u32) -> bool {
         if self.current >= n { self.current -= n; true } else { false }
     }
 }
 
+/// 이동 계열 — 지형별 이동 비용 정책(TerrainCostPolicy)의 키
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MovementClass {
+    /// 보병(기본): TerrainKind::is_passable_base 기준
+    #[default]
+    Infantry,
+    /// 비행: 물 위 통과
+    Flyer,
+    /// 수영: 물에서 이동
+    Swimmer,
+    /// 중장갑: 숲 등에서 감속
+    Heavy,
+}
+
 /// 공격 가능한 방향(4방향 / 8방향)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AimDirs {
@@ -63,7 +91,7 @@ pub enum AimDirs {
     Eight,  // 대각 포함
 }
 
-/// 공격 기본값 (데미지/사거리/치명타/방향)
+/// 공격 기본값 (데미지/사거리/치명타/방향/AP 비용)
 #[derive(Component, Debug, Clone, Copy)]
 pub struct Attack {
     pub damage: i32,        // 기본 피해
@@ -71,14 +99,28 @@ pub struct Attack {
     pub crit_chance: f32,   // 0.0~1.0
     pub crit_mult: f32,     // 배수(예: 1.5 = 150%)
     pub dirs: AimDirs,      // 4방향 또는 8방향
+    pub ap_cost: u32,       // 공격 1회당 AP
 }
 impl Default for Attack {
     fn default() -> Self {
-        Self { damage: 3, range: 3, crit_chance: 0.05, crit_mult: 1.5, dirs: AimDirs::Four }
+        Self { damage: 3, range: 3, crit_chance: 0.05, crit_mult: 1.5, dirs: AimDirs::Four, ap_cost: 1 }
     }
 }
 impl Attack {
     #[inline] pub fn allows_diagonal(&self) -> bool { matches!(self.dirs, AimDirs::Eight) }
+
+    /// from → to 조준선 (단위 방향, 칸 거리). 직선(Four)/대각(Eight) 위가 아니면 None
+    /// 사거리는 검사하지 않음(호출 측에서 distance와 range 비교)
+    pub fn aim_line(&self, from: GridPos, to: GridPos) -> Option<((i32, i32), u32)> {
+        let dx = to.x as i32 - from.x as i32;
+        let dy = to.y as i32 - from.y as i32;
+        if dx == 0 && dy == 0 { return None; }
+        let straight = dx == 0 || dy == 0;
+        let diagonal = dx.abs() == dy.abs();
+        if !(straight || (diagonal && self.allows_diagonal())) { return None; }
+        let distance = dx.unsigned_abs().max(dy.unsigned_abs());
+        Some(((dx.signum(), dy.signum()), distance))
+    }
 }
 
 /// 행동 특성(룰 변경) — 간단 플래그
@@ -92,6 +134,28 @@ pub struct ActionTraits {
     pub can_phase: bool,
 }
 
+/// 탄창 — 공격마다 탄 소모, 비면 공격 불가
+/// - ActionTraits::auto_reload: 자기 팀 페이즈 시작마다 가득 채움
+/// - 그 외: 재장전 명령으로 reload_cost AP를 써서 채움
+/// - 이 컴포넌트가 없는 유닛은 탄 제한 없음
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Magazine {
+    pub current: u32,
+    pub capacity: u32,
+    /// 재장전 AP 비용
+    pub reload_cost: u32,
+}
+impl Magazine {
+    pub fn new(capacity: u32, reload_cost: u32) -> Self { Self { current: capacity, capacity, reload_cost } }
+    #[inline] pub fn is_empty(&self) -> bool { self.current == 0 }
+    #[inline] pub fn is_full(&self) -> bool { self.current >= self.capacity }
+    #[inline] pub fn refill(&mut self) { self.current = self.capacity; }
+    /// n발 소모 (부족하면 false, 변화 없음)
+    #[inline] pub fn try_consume(&mut self, n: u32) -> bool {
+        if self.current >= n { self.current -= n; true } else { false }
+    }
+}
+
 /// 발사 모드 보정(연사/멀티샷/전방 난사/포물선)
 ///
 /// 멀티샷과 전방 난사는 **동시에** 활성화될 수 있음.
@@ -174,9 +238,80 @@ pub struct ElementalImbue {
     pub cooldown_max: u32,
     pub cooldown: u32,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Element { Fire, Ice, Electric, Poison }
 
+/// 걸려 있는 상태이상 — 속성별 1칸(중첩 규칙은 combat::statuses)
+/// turns = 남은 자기 팀 페이즈 수(페이즈 시작마다 효과 적용 후 1 감소)
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusEffects {
+    /// 화염: 페이즈 시작마다 damage
+    pub burning: Option<Burning>,
+    /// 빙결: 남은 이동력 상한 감소(per_turn은 그대로), frozen이면 이동 불가
+    pub chill: Option<Chill>,
+    /// 전기: 행동(AP) 불가
+    pub stun: Option<Stun>,
+    /// 독: 페이즈 시작마다 스택 비례 피해
+    pub poison: Option<Poison>,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Burning {
+    pub damage: i32,
+    pub turns: u32,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chill {
+    pub frozen: bool,
+    pub turns: u32,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stun {
+    pub turns: u32,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Poison {
+    pub stacks: u32,
+    pub turns: u32,
+}
+
+/// 유닛 레벨 (1부터)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level(pub u8);
+impl Default for Level {
+    fn default() -> Self { Self(1) }
+}
+
+/// 다음 레벨까지 모은 경험치 (레벨업 시 요구량만큼 차감)
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Xp {
+    pub current: u32,
+}
+
+/// 레벨 1단계 성장치 — `xp`를 모으면 다음 레벨로, 나머지는 그때 더해지는 값
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GrowthStep {
+    pub xp: u32,
+    pub max_hp: i32,
+    pub damage: i32,
+    pub move_per_turn: u32,
+    pub actions_per_turn: u32,
+    /// 열리는 퍼크 슬롯 수
+    pub perk_slots: u8,
+}
+
+/// 성장 곡선: steps[0] = 1 → 2레벨 (비어 있으면 레벨업 없음, 최대 레벨 = steps.len() + 1)
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Growth {
+    pub steps: Vec<GrowthStep>,
+}
+impl Growth {
+    /// level → level + 1 단계 (최대 레벨이면 None)
+    #[inline]
+    pub fn next(&self, level: Level) -> Option<&GrowthStep> {
+        self.steps.get((level.0 as usize).checked_sub(1)?)
+    }
+}
+
 /// 기본 유닛 번들 (뷰/스프라이트는 별도 레이어에서 관리)
 #[derive(Bundle, Debug)]
 pub struct UnitBundle {
@@ -185,11 +320,17 @@ pub struct UnitBundle {
     pub stats: Stats,
     pub move_budget: MoveBudget,
     pub action_budget: ActionBudget,
+    pub movement_class: MovementClass,
     pub attack: Attack,
     pub traits_: ActionTraits,
     pub firing: FiringMods,
     pub propagation: Propagation,
     pub on_hit: OnHitEffects,
     pub imbue: ElementalImbue,
+    pub statuses: StatusEffects,
+    pub perks: PerkSlots,
+    pub level: Level,
+    pub xp: Xp,
+    pub growth: Growth,
     pub name: Name,
 }
\ No newline at end of file
@@ -0,0 +1,34 @@
+// src/gameplay/units/events.rs
+use bevy::prelude::*;
+use crate::gameplay::tiles::components::GridPos;
+use super::components::{GrowthStep, Level};
+use super::validate::SpecError;
+
+/// 유닛 사망 (Stats::is_dead 감지 시 1회)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitDied {
+    pub entity: Entity,
+    /// 마지막 피해를 준 유닛 (같은 프레임의 DamageApplied 기준, 없으면 None)
+    pub killer: Option<Entity>,
+    /// 사망 위치 (그리드에 없던 유닛이면 None)
+    pub at: Option<GridPos>,
+}
+
+/// 레벨업 (성장치 반영 후, 한 번에 여러 레벨이면 레벨마다 1회)
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUp {
+    pub entity: Entity,
+    /// 새 레벨
+    pub level: Level,
+    /// 이번 레벨업으로 더해진 값
+    pub gains: GrowthStep,
+}
+
+/// 로드는 됐지만 값 검사에 걸린 스펙 — 이 스펙으로 예약된 스폰은 건너뜀
+/// (같은 프레임에 같은 스펙이 여러 번 예약돼 있어도 1회)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct UnitSpecInvalid {
+    /// 에셋 경로 (예: "units/enemy.ron")
+    pub path: String,
+    pub errors: Vec<SpecError>,
+}
@@ -0,0 +1,153 @@
+// src/gameplay/units/loader.rs
+//! UnitSpec 전용 에셋 로더: `base` 상속 체인을 풀어 완성 스펙 1개로 로드
+//!
+//! 규칙
+//! - 자식 → 부모 순으로 읽고, 가까운 쪽(자식)의 필드가 우선
+//! - base 경로는 assets/ 기준 ("units/base_soldier.ron")
+//! - 체인에 같은 파일이 두 번 나오면 순환 에러 (a → b → a)
+//! - 부모 파일은 로드 의존성으로 등록돼, 부모를 고치면 자식도 다시 로드됨
+//! - 부모는 불완전해도 됨(추상 베이스) — 필수 필드 검사는 체인 전체를 합친 뒤
+use std::fmt;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+
+use super::spec::{UnitSpec, UnitSpecPatch};
+
+/// 상속 체인 최대 깊이 (순환 검사와 별개의 안전장치)
+const MAX_BASE_DEPTH: usize = 16;
+
+/// `*.ron` → UnitSpec (상속 해석 포함)
+#[derive(Debug, Default)]
+pub struct UnitSpecLoader;
+
+/// 로드 실패 사유 — 어느 파일에서 무엇이 틀렸는지 경로를 붙여 보고
+#[derive(Debug)]
+pub enum UnitSpecLoadError {
+    /// 파일 읽기 실패
+    Io { path: String, error: std::io::Error },
+    /// base 파일을 찾을 수 없음/읽기 실패
+    Base { path: String, base: String, error: String },
+    /// RON 파싱 실패 (줄/열 포함)
+    Parse { path: String, error: ron::error::SpannedError },
+    /// 상속 순환 (체인 전체)
+    Cycle { chain: Vec<String> },
+    /// 상속이 너무 깊음
+    TooDeep { chain: Vec<String> },
+    /// 체인 전체를 합쳐도 빠진 필수 필드
+    Missing { path: String, fields: Vec<&'static str> },
+}
+
+impl fmt::Display for UnitSpecLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "unit spec '{path}': could not read file: {error}"),
+            Self::Base { path, base, error } => write!(f, "unit spec '{path}': could not read base '{base}': {error}"),
+            Self::Parse { path, error } => write!(f, "unit spec '{path}': {error}"),
+            Self::Cycle { chain } => write!(f, "unit spec base cycle: {}", chain.join(" -> ")),
+            Self::TooDeep { chain } => {
+                write!(f, "unit spec base chain deeper than {MAX_BASE_DEPTH}: {}", chain.join(" -> "))
+            }
+            Self::Missing { path, fields } => {
+                write!(f, "unit spec '{path}': missing fields after inheritance: {}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for UnitSpecLoadError {}
+
+impl AssetLoader for UnitSpecLoader {
+    type Asset = UnitSpec;
+    type Settings = ();
+    type Error = UnitSpecLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let path = load_context.path().to_string_lossy().replace('\\', "/");
+
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|error| UnitSpecLoadError::Io { path: path.clone(), error })?;
+        let mut spec = parse(&path, &bytes)?;
+
+        // 자식 → 부모로 올라가며 빈 필드 채움
+        let mut chain = vec![path.clone()];
+        while let Some(base) = spec.base.take() {
+            let from = chain.last().cloned().unwrap_or_default();
+            push_base(&mut chain, base.clone())?;
+
+            let base_bytes = load_context
+                .read_asset_bytes(base.clone())
+                .await
+                .map_err(|e| UnitSpecLoadError::Base { path: from, base: base.clone(), error: e.to_string() })?;
+            let parent = parse(&base, &base_bytes)?;
+            spec = spec.inherit(parent);
+        }
+
+        spec.into_spec().map_err(|fields| UnitSpecLoadError::Missing { path, fields })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// 체인 끝에 base를 이음 — 이미 체인에 있으면 순환, 너무 깊으면 에러 (에러의 체인은 base까지 포함)
+fn push_base(chain: &mut Vec<String>, base: String) -> Result<(), UnitSpecLoadError> {
+    let cycle = chain.contains(&base);
+    let too_deep = chain.len() >= MAX_BASE_DEPTH;
+    chain.push(base);
+    if cycle {
+        return Err(UnitSpecLoadError::Cycle { chain: chain.clone() });
+    }
+    if too_deep {
+        return Err(UnitSpecLoadError::TooDeep { chain: chain.clone() });
+    }
+    Ok(())
+}
+
+fn parse(path: &str, bytes: &[u8]) -> Result<UnitSpecPatch, UnitSpecLoadError> {
+    ron::de::from_bytes(bytes).map_err(|error| UnitSpecLoadError::Parse { path: path.to_string(), error })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn base_chain_accepts_new_files() {
+        let mut c = chain(&["units/ally.ron"]);
+        assert!(push_base(&mut c, "units/base_soldier.ron".into()).is_ok());
+        assert_eq!(c, chain(&["units/ally.ron", "units/base_soldier.ron"]));
+    }
+
+    #[test]
+    fn base_chain_reports_cycle_with_full_chain() {
+        let mut c = chain(&["units/a.ron", "units/b.ron"]);
+        let err = push_base(&mut c, "units/a.ron".into()).unwrap_err();
+        assert_eq!(err.to_string(), "unit spec base cycle: units/a.ron -> units/b.ron -> units/a.ron");
+    }
+
+    #[test]
+    fn base_chain_self_reference_is_cycle() {
+        let mut c = chain(&["units/a.ron"]);
+        assert!(matches!(push_base(&mut c, "units/a.ron".into()), Err(UnitSpecLoadError::Cycle { .. })));
+    }
+
+    #[test]
+    fn base_chain_depth_limit() {
+        let mut c: Vec<String> = (0..MAX_BASE_DEPTH).map(|i| format!("units/{i}.ron")).collect();
+        let err = push_base(&mut c, "units/next.ron".into()).unwrap_err();
+        assert!(matches!(err, UnitSpecLoadError::TooDeep { ref chain } if chain.len() == MAX_BASE_DEPTH + 1));
+    }
+}
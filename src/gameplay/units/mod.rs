@@ -1,11 +1,22 @@
 // src/gameplay/units/mod.rs
 pub mod components;
+pub mod events;
 pub mod resources;
 pub mod spec;
+pub mod loader;
+pub mod validate;
 pub mod assets;
+pub mod movement;
+pub mod terrain_costs;
+pub mod xp_rules;
 pub mod systems {
     pub mod spawn_from_assets;
+    pub mod terrain_costs;
+    pub mod lifecycle;
+    pub mod progression;
+    pub mod roster;
+    pub mod xp_rules;
 }
 pub mod plugin;
 
-pub use plugin::UnitsPlugin;
+pub use plugin::{UnitsPlugin, UnitLifecycleSet};
@@ -0,0 +1,82 @@
+// src/gameplay/units/movement.rs
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+
+use crate::gameplay::tiles::{
+    components::GridPos,
+    pathfinding::{Path, PathOptions, Pathfinder, Reachable},
+};
+use super::components::{ActionBudget, ActionTraits, MoveBudget, MovementClass, Unit};
+use super::terrain_costs::TerrainCostPolicy;
+
+type UnitMoveData = (
+    &'static GridPos,
+    &'static mut MoveBudget,
+    Option<&'static ActionTraits>,
+    Option<&'static MovementClass>,
+    Option<&'static ActionBudget>,
+);
+
+/// 유닛 엔티티 기준 이동 질의(경로/도달 영역) + 예산 차감
+/// - 이동 실행기, 이동 범위 하이라이트, AI가 같은 규칙을 공유
+#[derive(SystemParam)]
+pub struct UnitMovement<'w, 's> {
+    pub pathfinder: Pathfinder<'w>,
+    policy: Res<'w, TerrainCostPolicy>,
+    q_units: Query<'w, 's, UnitMoveData, With<Unit>>,
+}
+
+impl<'w, 's> UnitMovement<'w, 's> {
+    /// 유닛의 현재 칸 (유닛이 아니거나 아직 배치 전이면 None)
+    #[inline]
+    pub fn position(&self, unit: Entity) -> Option<GridPos> {
+        self.q_units.get(unit).ok().map(|(p, ..)| *p)
+    }
+
+    /// 유닛의 남은 이동 예산
+    #[inline]
+    pub fn budget(&self, unit: Entity) -> Option<u32> {
+        self.q_units.get(unit).ok().map(|(_, b, ..)| b.current)
+    }
+
+    /// 공격 후라 이동할 수 없는지 (ActionTraits::can_move_after_attack가 없으면 공격 후 이동 불가)
+    pub fn locked_after_attack(&self, unit: Entity) -> bool {
+        self.q_units.get(unit).is_ok_and(|(_, _, traits_, _, ap)| {
+            ap.is_some_and(|ap| ap.attacked) && !traits_.is_some_and(|t| t.can_move_after_attack)
+        })
+    }
+
+    /// 유닛 특성/이동 계열 → 경로 탐색 옵션
+    pub fn options(&self, unit: Entity) -> Option<PathOptions> {
+        let (_, _, traits_, class, _) = self.q_units.get(unit).ok()?;
+        let costs = self.policy.costs(class.copied().unwrap_or_default());
+        Some(
+            PathOptions::default()
+                .with_costs(costs)
+                .with_phase(traits_.is_some_and(|t| t.can_phase)),
+        )
+    }
+
+    /// 현재 MoveBudget::current로 도달 가능한 영역 (공격 후 이동 불가면 예산 0)
+    pub fn reachable(&mut self, unit: Entity) -> Option<Reachable> {
+        let opts = self.options(unit)?;
+        let locked = self.locked_after_attack(unit);
+        let (&from, budget, ..) = self.q_units.get(unit).ok()?;
+        let budget = if locked { 0 } else { budget.current };
+        self.pathfinder.reachable(from, budget, &opts)
+    }
+
+    /// 예산과 무관한 최단 경로 (거부 사유 판별/미리보기용)
+    pub fn path_to(&mut self, unit: Entity, to: GridPos) -> Option<Path> {
+        let opts = self.options(unit)?;
+        let from = self.position(unit)?;
+        self.pathfinder.find_path(from, to, &opts)
+    }
+
+    /// 이동 예산 차감 (부족하면 false, 변화 없음)
+    pub fn try_spend(&mut self, unit: Entity, cost: u32) -> bool {
+        self.q_units
+            .get_mut(unit)
+            .is_ok_and(|(_, mut b, ..)| b.try_spend(cost))
+    }
+}
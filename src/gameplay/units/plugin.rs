@@ -3,21 +3,59 @@ use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
 use crate::app::state::AppState;
 use crate::gameplay::tiles::plugin::TilesSet;
+use crate::gameplay::combat::CombatSet;
 use crate::gameplay::units::systems::spawn_from_assets::has_pending; // TilesSet가 pub이어야 함
 
 use super::spec::UnitSpec;
+use super::loader::UnitSpecLoader;
+use super::events::{LevelUp, UnitDied, UnitSpecInvalid};
+use super::resources::{DeathConfig, PlayerTeamId, SquadRoster, XpConfig};
 use super::assets::PendingUnitLoads;
+use super::terrain_costs::{TerrainCostTable, TerrainCostPolicy, TerrainCostTableHandle};
 use super::systems::spawn_from_assets::{ process_loaded_units};
+use super::systems::terrain_costs::{load_terrain_cost_table, apply_terrain_cost_table};
+use super::systems::xp_rules::{load_xp_rules, apply_xp_rules};
+use super::xp_rules::{XpRules, XpRulesHandle};
+use super::systems::lifecycle::{detect_unit_deaths, cleanup_dead_units};
+use super::systems::progression::{award_xp, apply_level_ups};
+use super::systems::roster::sync_squad_roster;
+use crate::gameplay::interaction::InteractionSet;
+
+/// 유닛 생명주기 순서 정의 (뷰 등 다른 모듈은 시스템 대신 이 세트 기준으로 순서를 잡음)
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitLifecycleSet {
+    /// 사망 감지 + UnitDied 발행 + 그리드에서 제거
+    Detect,
+    /// 사망 유닛 despawn 또는 시체 전환
+    Cleanup,
+}
 
 pub struct UnitsPlugin;
 
 impl Plugin for UnitsPlugin {
     fn build(&self, app: &mut App) {
         app
-            // RON 스펙 로더 등록
-            .add_plugins(RonAssetPlugin::<UnitSpec>::new(&["ron"]))
+            // RON 스펙 로더 등록 (base 상속 해석)
+            .init_asset::<UnitSpec>()
+            .register_asset_loader(UnitSpecLoader)
+            // 이동 계열별 지형 비용 테이블 (*.costs.ron)
+            .add_plugins(RonAssetPlugin::<TerrainCostTable>::new(&["costs.ron"]))
+            // 경험치 획득 규칙 (*.xp.ron)
+            .add_plugins(RonAssetPlugin::<XpRules>::new(&["xp.ron"]))
             // 리소스
             .init_resource::<PendingUnitLoads>()
+            .init_resource::<TerrainCostPolicy>()
+            .init_resource::<TerrainCostTableHandle>()
+            .init_resource::<DeathConfig>()
+            .init_resource::<XpConfig>()
+            .init_resource::<XpRulesHandle>()
+            .init_resource::<PlayerTeamId>()
+            .init_resource::<SquadRoster>()
+            .add_event::<UnitDied>()
+            .add_event::<LevelUp>()
+            .add_event::<UnitSpecInvalid>()
+            .add_systems(Startup, (load_terrain_cost_table, load_xp_rules))
+            .add_systems(Update, (apply_terrain_cost_table, apply_xp_rules))
             // 로드 처리: 타일 적용과 같은 프레임에 일어나게 PreUpdate에서,
             // TilesSet::ApplyCommands 전에 실행
             .add_systems(
@@ -26,6 +64,36 @@ impl Plugin for UnitsPlugin {
                     .run_if(in_state(AppState::Battle))
                     .run_if(has_pending)
                     .before(TilesSet::ApplyCommands),
+            )
+            // 사망 처리: 피해 해석(CombatSet::Resolve) 직후 감지 → 그리드 반영 후 정리
+            .configure_sets(
+                Update,
+                (UnitLifecycleSet::Detect, UnitLifecycleSet::Cleanup)
+                    .chain()
+                    .after(CombatSet::Resolve),
+            )
+            .add_systems(
+                Update,
+                (
+                    detect_unit_deaths.in_set(UnitLifecycleSet::Detect),
+                    cleanup_dead_units.in_set(UnitLifecycleSet::Cleanup),
+                )
+                    .run_if(in_state(AppState::Battle)),
+            )
+            // 분대 명단: 핫키 해석 전에 스폰/사망 반영
+            .add_systems(
+                Update,
+                sync_squad_roster
+                    .before(InteractionSet::Interpret)
+                    .run_if(in_state(AppState::Battle)),
+            )
+            // 성장: 사망 감지 뒤 경험치 적립 → 레벨업 (이번 피해로 죽은 유닛은 레벨업 제외)
+            .add_systems(
+                Update,
+                (award_xp, apply_level_ups)
+                    .chain()
+                    .after(UnitLifecycleSet::Detect)
+                    .run_if(in_state(AppState::Battle)),
             );
     }
 }
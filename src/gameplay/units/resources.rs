@@ -21,6 +21,33 @@ impl Default for Teams {
     }
 }
 
+/// 플레이어 분대 명단 — 플레이어 팀 유닛을 스폰 순서대로 (사망 시 제외)
+/// - 핫키 순환 선택과 분대 UI 줄의 순서 기준
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SquadRoster {
+    pub units: Vec<Entity>,
+}
+
+impl SquadRoster {
+    /// from 다음(backward면 이전)부터 명단을 한 바퀴 돌며 ready인 첫 유닛
+    /// - from이 없거나 명단에 없으면 처음(backward면 끝)부터
+    /// - from 자신은 마지막 후보(혼자 남았으면 그대로 유지)
+    pub fn cycle(&self, from: Option<Entity>, backward: bool, ready: impl Fn(Entity) -> bool) -> Option<Entity> {
+        let n = self.units.len();
+        if n == 0 { return None; }
+        let start = from.and_then(|e| self.units.iter().position(|&u| u == e));
+        (1..=n)
+            .map(|k| match (start, backward) {
+                (Some(i), false) => (i + k) % n,
+                (Some(i), true) => (i + n - k % n) % n,
+                (None, false) => k - 1,
+                (None, true) => n - k,
+            })
+            .map(|i| self.units[i])
+            .find(|&e| ready(e))
+    }
+}
+
 /// 팀 색상(디버그/뷰용)
 #[derive(Resource, Debug, Clone, Copy)]
 pub struct TeamColors {
@@ -35,3 +62,54 @@ impl Default for TeamColors {
         }
     }
 }
+
+/// 경험치 획득 규칙 (rules/progression.xp.ron에서 로드, 로드 전에는 0)
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct XpConfig {
+    /// 적에게 준 피해 1당(쉴드 흡수 포함)
+    pub per_damage: u32,
+    /// 적 처치 1회당
+    pub per_kill: u32,
+}
+/// 사망 처리 설정
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct DeathConfig {
+    /// true면 엔티티를 지우지 않고 Corpse 마커만 남김
+    pub leave_corpse: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster(n: u32) -> SquadRoster {
+        SquadRoster { units: (1..=n).map(Entity::from_raw).collect() }
+    }
+
+    fn e(id: u32) -> Entity { Entity::from_raw(id) }
+
+    #[test]
+    fn cycle_wraps_around_both_ways() {
+        let r = roster(3);
+        let all = |_| true;
+        assert_eq!(r.cycle(Some(e(3)), false, all), Some(e(1)));
+        assert_eq!(r.cycle(Some(e(1)), true, all), Some(e(3)));
+        assert_eq!(r.cycle(Some(e(1)), false, all), Some(e(2)));
+        // 선택이 없거나 명단 밖이면 처음(역방향은 끝)부터
+        assert_eq!(r.cycle(None, false, all), Some(e(1)));
+        assert_eq!(r.cycle(Some(e(9)), true, all), Some(e(3)));
+    }
+
+    #[test]
+    fn cycle_skips_units_with_no_budget_left() {
+        let r = roster(4);
+        let ready = |u: Entity| u != e(2) && u != e(3);
+        assert_eq!(r.cycle(Some(e(1)), false, ready), Some(e(4)));
+        assert_eq!(r.cycle(Some(e(4)), true, ready), Some(e(1)));
+
+        // 자기 자신만 남았으면 그대로, 아무도 없으면 None
+        assert_eq!(r.cycle(Some(e(1)), false, |u| u == e(1)), Some(e(1)));
+        assert_eq!(r.cycle(Some(e(1)), false, |_| false), None);
+        assert_eq!(roster(0).cycle(None, false, |_| true), None);
+    }
+}
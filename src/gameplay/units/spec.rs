@@ -1,12 +1,13 @@
 // src/gameplay/units/spec.rs
 use bevy::{asset::Asset, prelude::*, reflect::TypePath};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
 use crate::gameplay::{
+    perks::components::PerkSlots,
     tiles::components::GridPos,
     units::components::{
-        ActionBudget, ActionTraits, AimDirs, Attack, ElementalImbue, Element, FiringMods, OnHitEffects,
-        Propagation, Stats, TeamId, UnitBundle,
+        ActionBudget, ActionTraits, AimDirs, Attack, ElementalImbue, Element, FiringMods, Growth, GrowthStep, Level,
+        Magazine, MovementClass, OnHitEffects, Propagation, Stats, StatusEffects, TeamId, UnitBundle, Xp,
     },
 };
 
@@ -23,6 +24,8 @@ pub struct UnitSpec {
     // 예산
     pub move_per_turn: u32,
     pub actions_per_turn: u32,
+    /// 이동 계열(지형 비용 테이블의 키)
+    pub movement_class: MovementClassSpec,
 
     // 전투/특성/발사/전파/OnHit/속성
     pub attack: AttackSpec,
@@ -31,6 +34,135 @@ pub struct UnitSpec {
     pub propagation: PropagationSpec,
     pub on_hit: OnHitEffectsSpec,
     pub imbue: ElementalImbueSpec,
+    /// 탄창 (None이면 탄 제한 없음)
+    #[serde(default)]
+    pub magazine: Option<MagazineSpec>,
+    /// 처음부터 열려 있는 퍼크 슬롯 수
+    #[serde(default)]
+    pub perk_slots: u8,
+    /// 레벨별 성장 곡선 (growth[0] = 1 → 2레벨, 비우면 레벨업 없음)
+    #[serde(default)]
+    pub growth: Vec<GrowthStepSpec>,
+}
+
+/// 부분 스펙(RON 파일 1개): 적힌 필드만 Some, 나머지는 base에서 상속
+/// - `base`: 상속할 스펙 경로 (assets/ 기준, 예: "units/base_soldier.ron")
+/// - 필드 단위 덮어쓰기: attack/firing 같은 묶음은 통째로 교체(내부 필드 병합 없음)
+/// - `magazine: None`은 "상속"이 아니라 "탄 제한 없음"으로 덮어씀
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UnitSpecPatch {
+    #[serde(default, deserialize_with = "present")] pub base: Option<String>,
+    #[serde(default, deserialize_with = "present")] pub name: Option<String>,
+    #[serde(default, deserialize_with = "present")] pub team: Option<u8>,
+    #[serde(default, deserialize_with = "present")] pub max_hp: Option<i32>,
+    #[serde(default, deserialize_with = "present")] pub max_shield: Option<i32>,
+    #[serde(default, deserialize_with = "present")] pub move_per_turn: Option<u32>,
+    #[serde(default, deserialize_with = "present")] pub actions_per_turn: Option<u32>,
+    #[serde(default, deserialize_with = "present")] pub movement_class: Option<MovementClassSpec>,
+    #[serde(default, deserialize_with = "present")] pub attack: Option<AttackSpec>,
+    #[serde(default, deserialize_with = "present")] pub traits_: Option<ActionTraitsSpec>,
+    #[serde(default, deserialize_with = "present")] pub firing: Option<FiringModsSpec>,
+    #[serde(default, deserialize_with = "present")] pub propagation: Option<PropagationSpec>,
+    #[serde(default, deserialize_with = "present")] pub on_hit: Option<OnHitEffectsSpec>,
+    #[serde(default, deserialize_with = "present")] pub imbue: Option<ElementalImbueSpec>,
+    #[serde(default, deserialize_with = "present")] pub magazine: Option<Option<MagazineSpec>>,
+    #[serde(default, deserialize_with = "present")] pub perk_slots: Option<u8>,
+    #[serde(default, deserialize_with = "present")] pub growth: Option<Vec<GrowthStepSpec>>,
+}
+
+/// 필드가 적혀 있으면 Some(값) — RON의 Some(..) 표기 없이 "적혔는지"만 구분
+fn present<'de, D: Deserializer<'de>, T: Deserialize<'de>>(d: D) -> Result<Option<T>, D::Error> {
+    T::deserialize(d).map(Some)
+}
+
+impl UnitSpecPatch {
+    /// 비어 있는 필드를 base에서 채움 (base의 base는 base 쪽에 이미 반영돼 있어야 함)
+    pub fn inherit(self, base: UnitSpecPatch) -> UnitSpecPatch {
+        UnitSpecPatch {
+            base: base.base,
+            name: self.name.or(base.name),
+            team: self.team.or(base.team),
+            max_hp: self.max_hp.or(base.max_hp),
+            max_shield: self.max_shield.or(base.max_shield),
+            move_per_turn: self.move_per_turn.or(base.move_per_turn),
+            actions_per_turn: self.actions_per_turn.or(base.actions_per_turn),
+            movement_class: self.movement_class.or(base.movement_class),
+            attack: self.attack.or(base.attack),
+            traits_: self.traits_.or(base.traits_),
+            firing: self.firing.or(base.firing),
+            propagation: self.propagation.or(base.propagation),
+            on_hit: self.on_hit.or(base.on_hit),
+            imbue: self.imbue.or(base.imbue),
+            magazine: self.magazine.or(base.magazine),
+            perk_slots: self.perk_slots.or(base.perk_slots),
+            growth: self.growth.or(base.growth),
+        }
+    }
+
+    /// 상속을 모두 반영한 뒤 완성 스펙으로 — 빠진 필수 필드 이름 목록을 에러로
+    pub fn into_spec(self) -> Result<UnitSpec, Vec<&'static str>> {
+        let missing: Vec<&'static str> = [
+            ("name", self.name.is_none()),
+            ("team", self.team.is_none()),
+            ("max_hp", self.max_hp.is_none()),
+            ("max_shield", self.max_shield.is_none()),
+            ("move_per_turn", self.move_per_turn.is_none()),
+            ("actions_per_turn", self.actions_per_turn.is_none()),
+            ("movement_class", self.movement_class.is_none()),
+            ("attack", self.attack.is_none()),
+            ("traits_", self.traits_.is_none()),
+            ("firing", self.firing.is_none()),
+            ("propagation", self.propagation.is_none()),
+            ("on_hit", self.on_hit.is_none()),
+            ("imbue", self.imbue.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(field, none)| none.then_some(field))
+        .collect();
+
+        let (
+            Some(name), Some(team), Some(max_hp), Some(max_shield), Some(move_per_turn), Some(actions_per_turn),
+            Some(movement_class), Some(attack), Some(traits_), Some(firing), Some(propagation), Some(on_hit), Some(imbue),
+        ) = (
+            self.name, self.team, self.max_hp, self.max_shield, self.move_per_turn, self.actions_per_turn,
+            self.movement_class, self.attack, self.traits_, self.firing, self.propagation, self.on_hit, self.imbue,
+        ) else {
+            return Err(missing);
+        };
+
+        Ok(UnitSpec {
+            name,
+            team,
+            max_hp,
+            max_shield,
+            move_per_turn,
+            actions_per_turn,
+            movement_class,
+            attack,
+            traits_,
+            firing,
+            propagation,
+            on_hit,
+            imbue,
+            magazine: self.magazine.flatten(),
+            perk_slots: self.perk_slots.unwrap_or_default(),
+            growth: self.growth.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum MovementClassSpec { Infantry, Flyer, Swimmer, Heavy }
+
+impl From<MovementClassSpec> for MovementClass {
+    fn from(s: MovementClassSpec) -> Self {
+        match s {
+            MovementClassSpec::Infantry => MovementClass::Infantry,
+            MovementClassSpec::Flyer => MovementClass::Flyer,
+            MovementClassSpec::Swimmer => MovementClass::Swimmer,
+            MovementClassSpec::Heavy => MovementClass::Heavy,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Deserialize)]
@@ -43,6 +175,8 @@ pub struct AttackSpec {
     pub crit_chance: f32,
     pub crit_mult: f32,
     pub dirs: AimDirsSpec,
+    /// 공격 1회당 AP
+    pub ap_cost: u32,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize)]
@@ -52,6 +186,36 @@ pub struct ActionTraitsSpec {
     pub can_phase: bool,
 }
 
+/// 레벨 1단계: 필요 경험치 + 증가량(생략하면 0)
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct GrowthStepSpec {
+    pub xp: u32,
+    #[serde(default)] pub max_hp: i32,
+    #[serde(default)] pub damage: i32,
+    #[serde(default)] pub move_per_turn: u32,
+    #[serde(default)] pub actions_per_turn: u32,
+    #[serde(default)] pub perk_slots: u8,
+}
+
+impl From<GrowthStepSpec> for GrowthStep {
+    fn from(s: GrowthStepSpec) -> Self {
+        Self {
+            xp: s.xp,
+            max_hp: s.max_hp,
+            damage: s.damage,
+            move_per_turn: s.move_per_turn,
+            actions_per_turn: s.actions_per_turn,
+            perk_slots: s.perk_slots,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct MagazineSpec {
+    pub capacity: u32,
+    pub reload_cost: u32,
+}
+
 #[derive(Debug, Clone, Copy, Deserialize)]
 pub struct FiringModsSpec {
     pub burst: u8,
@@ -82,9 +246,20 @@ pub struct OnHitEffectsSpec {
     pub gravity: Option<GravityPullSpec>,
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 pub enum ElementSpec { Fire, Ice, Electric, Poison }
 
+impl From<ElementSpec> for Element {
+    fn from(s: ElementSpec) -> Self {
+        match s {
+            ElementSpec::Fire => Element::Fire,
+            ElementSpec::Ice => Element::Ice,
+            ElementSpec::Electric => Element::Electric,
+            ElementSpec::Poison => Element::Poison,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Deserialize)]
 pub struct ElementalImbueSpec {
     pub element: Option<ElementSpec>,
@@ -101,6 +276,7 @@ impl UnitSpec {
             stats: Stats::new(self.max_hp, self.max_shield),
             move_budget: crate::gameplay::units::components::MoveBudget::new(self.move_per_turn),
             action_budget: ActionBudget::new(self.actions_per_turn),
+            movement_class: self.movement_class.into(),
             attack: Attack {
                 damage: self.attack.damage,
                 range: self.attack.range,
@@ -110,6 +286,7 @@ impl UnitSpec {
                     AimDirsSpec::Four => AimDirs::Four,
                     AimDirsSpec::Eight => AimDirs::Eight,
                 },
+                ap_cost: self.attack.ap_cost,
             },
             traits_: ActionTraits {
                 can_move_after_attack: self.traits_.can_move_after_attack,
@@ -140,18 +317,23 @@ impl UnitSpec {
                 }),
             },
             imbue: ElementalImbue {
-                element: self.imbue.element.map(|el| match el {
-                    ElementSpec::Fire => Element::Fire,
-                    ElementSpec::Ice => Element::Ice,
-                    ElementSpec::Electric => Element::Electric,
-                    ElementSpec::Poison => Element::Poison,
-                }),
+                element: self.imbue.element.map(Element::from),
                 cooldown_max: self.imbue.cooldown_max,
                 cooldown: self.imbue.cooldown,
             },
+            statuses: StatusEffects::default(),
+            perks: PerkSlots::new(self.perk_slots),
+            level: Level::default(),
+            xp: Xp::default(),
+            growth: Growth { steps: self.growth.iter().map(|&s| s.into()).collect() },
             name: Name::new(self.name.clone()),
         }
     }
+
+    /// 탄창 컴포넌트 (번들과 별도로 붙임 — 없는 유닛은 탄 제한 없음)
+    pub fn magazine(&self) -> Option<Magazine> {
+        self.magazine.map(|m| Magazine::new(m.capacity, m.reload_cost))
+    }
 }
 
 /// (옵션) 스폰 계획용 데이터
@@ -160,3 +342,79 @@ pub struct UnitSpawnPlan {
     pub asset_path: &'static str, // 예: "units/ally.ron"
     pub at: GridPos,              // 어느 타일에 놓을지
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = r#"(
+        max_hp: 10,
+        max_shield: 1,
+        move_per_turn: 4,
+        actions_per_turn: 1,
+        movement_class: Infantry,
+        attack: (damage: 3, range: 3, crit_chance: 0.05, crit_mult: 1.5, dirs: Four, ap_cost: 1),
+        traits_: (can_move_after_attack: false, auto_reload: false, can_phase: false),
+        firing: (burst: 0, multishot_rays: 0, fan_rays: 0, lobbed: false),
+        propagation: None,
+        on_hit: (explode: None, knockback: None, gravity: None),
+        imbue: (element: None, cooldown_max: 0, cooldown: 0),
+        magazine: Some((capacity: 4, reload_cost: 1)),
+        perk_slots: 1,
+    )"#;
+
+    fn patch(src: &str) -> UnitSpecPatch {
+        ron::from_str(src).unwrap()
+    }
+
+    #[test]
+    fn child_fields_win_and_missing_fields_inherit() {
+        let child = patch(r#"(base: "units/base.ron", name: "Child", team: 1, max_hp: 20)"#);
+        assert_eq!(child.base.as_deref(), Some("units/base.ron"));
+
+        let spec = child.inherit(patch(BASE)).into_spec().unwrap();
+        assert_eq!(spec.name, "Child");
+        assert_eq!(spec.max_hp, 20);
+        assert_eq!(spec.max_shield, 1);
+        assert_eq!(spec.perk_slots, 1);
+        assert_eq!(spec.magazine.map(|m| m.capacity), Some(4));
+    }
+
+    #[test]
+    fn explicit_none_overrides_base_magazine() {
+        // magazine: None은 "상속"이 아니라 "탄 제한 없음"
+        let child = patch(r#"(name: "Child", team: 1, magazine: None)"#);
+        assert!(matches!(child.magazine, Some(None)));
+        let spec = child.inherit(patch(BASE)).into_spec().unwrap();
+        assert!(spec.magazine.is_none());
+
+        // 생략하면 base 것을 그대로
+        let spec = patch(r#"(name: "Child", team: 1)"#).inherit(patch(BASE)).into_spec().unwrap();
+        assert!(spec.magazine.is_some());
+    }
+
+    #[test]
+    fn grandparent_fills_what_parent_leaves_out() {
+        let parent = patch(r#"(base: "units/base.ron", team: 1, max_hp: 15)"#);
+        let child = patch(r#"(base: "units/parent.ron", name: "Child")"#);
+        let merged = child.inherit(parent);
+        assert_eq!(merged.base.as_deref(), Some("units/base.ron"));
+        let spec = merged.inherit(patch(BASE)).into_spec().unwrap();
+        assert_eq!((spec.team, spec.max_hp, spec.move_per_turn), (1, 15, 4));
+    }
+
+    #[test]
+    fn into_spec_lists_missing_fields() {
+        let missing = patch(r#"(name: "Lonely", max_hp: 3)"#).into_spec().unwrap_err();
+        assert_eq!(
+            missing,
+            [
+                "team", "max_shield", "move_per_turn", "actions_per_turn", "movement_class", "attack", "traits_",
+                "firing", "propagation", "on_hit", "imbue",
+            ]
+        );
+        // 선택 필드(magazine/perk_slots/growth)는 없어도 됨
+        let spec = patch(BASE).inherit(patch(r#"(name: "Full", team: 0)"#)).into_spec().unwrap();
+        assert!(spec.growth.is_empty());
+    }
+}
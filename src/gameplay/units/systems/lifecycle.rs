@@ -0,0 +1,73 @@
+// src/gameplay/units/systems/lifecycle.rs
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::gameplay::combat::events::DamageApplied;
+use crate::gameplay::interaction::{SelectionChanged, SelectionCtx};
+use crate::gameplay::tiles::{components::GridPos, events::GridRemove};
+use crate::gameplay::units::{
+    components::{Corpse, Dead, Stats, Unit},
+    events::UnitDied,
+    resources::DeathConfig,
+};
+
+/// 이번 프레임에 Stats가 바뀐 (아직 살아 있던) 유닛
+type StatsChangedUnit = (With<Unit>, Changed<Stats>);
+
+/// 1) 사망 감지: Stats가 바뀐 유닛 중 is_dead → UnitDied + GridRemove + 선택 해제
+/// - Unit 마커를 떼어 즉시 선택/타깃/행동 대상에서 제외
+/// - 실제 despawn/시체 전환은 그리드 제거가 반영된 뒤 cleanup_dead_units에서
+pub fn detect_unit_deaths(
+    mut commands: Commands,
+    mut ev_damage: EventReader<DamageApplied>,
+    q_dead: Query<(Entity, &Stats, Option<&GridPos>), StatsChangedUnit>,
+    mut sel: ResMut<SelectionCtx>,
+    mut ev_died: EventWriter<UnitDied>,
+    mut ev_remove: EventWriter<GridRemove>,
+    mut ev_sel: EventWriter<SelectionChanged>,
+) {
+    // 같은 프레임의 마지막 가해자
+    let killers: HashMap<Entity, Entity> = ev_damage
+        .read()
+        .filter_map(|d| d.source.map(|s| (d.target, s)))
+        .collect();
+
+    for (entity, stats, pos) in &q_dead {
+        if !stats.is_dead() { continue; }
+
+        let at = pos.copied();
+        commands.entity(entity).remove::<Unit>().insert(Dead);
+        if let Some(at) = at {
+            ev_remove.write(GridRemove { entity, at });
+        }
+        ev_died.write(UnitDied { entity, killer: killers.get(&entity).copied(), at });
+
+        if sel.selected_unit == Some(entity) {
+            let tile = sel.selected_tile;
+            let (tile_changed, unit_changed) = sel.set_selection(tile, None);
+            ev_sel.write(SelectionChanged { tile_changed, unit_changed });
+        }
+    }
+}
+
+/// 2) 정리: 그리드에서 빠진(GridPos 없음) 사망 유닛을 despawn 또는 시체로 전환
+pub fn cleanup_dead_units(
+    mut commands: Commands,
+    cfg: Res<DeathConfig>,
+    q: Query<Entity, (With<Dead>, Without<GridPos>)>,
+    mut ev_died: EventReader<UnitDied>,
+    mut last_pos: Local<HashMap<Entity, Option<GridPos>>>,
+) {
+    for e in ev_died.read() {
+        last_pos.insert(e.entity, e.at);
+    }
+    for entity in &q {
+        let at = last_pos.remove(&entity).flatten();
+        if cfg.leave_corpse {
+            commands.entity(entity).remove::<Dead>().insert(Corpse { at });
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+}
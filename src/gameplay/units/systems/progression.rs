@@ -0,0 +1,161 @@
+// src/gameplay/units/systems/progression.rs
+use bevy::prelude::*;
+
+use crate::gameplay::combat::events::DamageApplied;
+use crate::gameplay::perks::components::PerkSlots;
+use crate::gameplay::units::{
+    components::{ActionBudget, Attack, Growth, Level, MoveBudget, Stats, TeamId, Unit, Xp},
+    events::LevelUp,
+    resources::XpConfig,
+};
+
+/// 레벨업 때 바뀌는 유닛 컴포넌트
+type Grower = (
+    Entity,
+    &'static mut Level,
+    &'static mut Xp,
+    &'static Growth,
+    &'static mut Stats,
+    &'static mut Attack,
+    &'static mut MoveBudget,
+    &'static mut ActionBudget,
+    Option<&'static mut PerkSlots>,
+);
+
+/// 이번 프레임에 Xp가 바뀐 (살아 있는) 유닛 — 사망 처리로 Unit이 떨어진 엔티티 제외
+type XpChangedUnit = (With<Unit>, Changed<Xp>);
+
+/// DamageApplied → 가해자 경험치 (피해량 + 처치)
+/// - 다른 팀에게 준 피해만(아군 오사/환경 피해 제외)
+/// - 이미 죽은 가해자(동시 사망 등)도 Xp가 남아 있으면 적립
+pub fn award_xp(
+    mut ev_damage: EventReader<DamageApplied>,
+    cfg: Res<XpConfig>,
+    q_team: Query<&TeamId>,
+    mut q_xp: Query<&mut Xp>,
+) {
+    for d in ev_damage.read() {
+        let Some(source) = d.source else { continue; };
+        let (Ok(a), Ok(b)) = (q_team.get(source), q_team.get(d.target)) else { continue; };
+        if a == b { continue; }
+        let Ok(mut xp) = q_xp.get_mut(source) else { continue; };
+
+        let dealt = (d.breakdown.absorbed + d.breakdown.hp_damage).max(0) as u32;
+        let kill = if d.breakdown.killed { cfg.per_kill } else { 0 };
+        xp.current = xp.current.saturating_add(dealt * cfg.per_damage + kill);
+    }
+}
+
+/// Xp가 바뀐 유닛 → 성장 곡선대로 레벨업(여러 단계 가능) + LevelUp
+/// - 최대 체력 증가분만큼 현재 체력도 회복
+/// - 이동/AP 증가는 per_turn에만(다음 페이즈 리셋부터 반영)
+/// - 죽은 유닛은 경험치만 쌓이고 레벨업하지 않음(체력이 되살아나지 않도록)
+pub fn apply_level_ups(
+    mut q_units: Query<Grower, XpChangedUnit>,
+    mut ev_level: EventWriter<LevelUp>,
+) {
+    for (entity, mut level, mut xp, growth, mut stats, mut attack, mut mv, mut ap, mut perks) in &mut q_units {
+        if stats.is_dead() { continue; }
+        while let Some(&gains) = growth.next(*level).filter(|s| xp.current >= s.xp) {
+            xp.current -= gains.xp;
+            level.0 += 1;
+
+            stats.max_hp += gains.max_hp;
+            stats.hp += gains.max_hp;
+            stats.clamp();
+            attack.damage += gains.damage;
+            mv.per_turn += gains.move_per_turn;
+            ap.per_turn += gains.actions_per_turn;
+            if let Some(perks) = perks.as_mut() {
+                perks.unlocked = perks.unlocked.saturating_add(gains.perk_slots);
+            }
+            ev_level.write(LevelUp { entity, level: *level, gains });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::gameplay::combat::attack::apply_damage;
+    use crate::gameplay::units::components::GrowthStep;
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<DamageApplied>>();
+        world.init_resource::<Events<LevelUp>>();
+        world.insert_resource(XpConfig { per_damage: 1, per_kill: 5 });
+        world
+    }
+
+    /// 단계마다 xp 5, 최대 체력 +2, 피해 +1
+    fn grower(world: &mut World, team: u8, steps: usize) -> Entity {
+        let step = GrowthStep { xp: 5, max_hp: 2, damage: 1, ..GrowthStep::default() };
+        let growth = Growth { steps: vec![step; steps] };
+        let parts = (Level::default(), Xp::default(), growth, Stats::new(10, 0), Attack::default());
+        world.spawn((Unit, TeamId(team), parts, MoveBudget::new(4), ActionBudget::new(2))).id()
+    }
+
+    /// source가 target에게 amount 피해 (체력 10 기준)
+    fn hit(world: &mut World, source: Entity, target: Entity, amount: i32) {
+        let breakdown = apply_damage(Stats::new(10, 0), amount, amount, false);
+        world.send_event(DamageApplied { source: Some(source), target, breakdown });
+        world.run_system_once(award_xp).unwrap();
+        world.run_system_once(apply_level_ups).unwrap();
+    }
+
+    fn levels(world: &mut World) -> Vec<u8> {
+        world.resource_mut::<Events<LevelUp>>().drain().map(|e| e.level.0).collect()
+    }
+
+    #[test]
+    fn crossing_threshold_levels_up_and_keeps_remainder() {
+        let mut world = world();
+        let shooter = grower(&mut world, 0, 3);
+        let enemy = grower(&mut world, 1, 0);
+
+        hit(&mut world, shooter, enemy, 4);
+        assert_eq!(world.get::<Level>(shooter).unwrap().0, 1);
+        world.resource_mut::<Events<DamageApplied>>().clear();
+
+        hit(&mut world, shooter, enemy, 3);
+        assert_eq!(levels(&mut world), [2]);
+        let e = world.entity(shooter);
+        assert_eq!(e.get::<Xp>().unwrap().current, 2);
+        let stats = e.get::<Stats>().unwrap();
+        assert_eq!((stats.max_hp, stats.hp), (12, 12));
+        assert_eq!(e.get::<Attack>().unwrap().damage, Attack::default().damage + 1);
+    }
+
+    #[test]
+    fn one_big_award_can_climb_several_levels_up_to_the_cap() {
+        let mut world = world();
+        let shooter = grower(&mut world, 0, 2);
+        let enemy = grower(&mut world, 1, 0);
+
+        // 처치 10 + 5 = 15xp → 2단계(최대)까지, 남은 5는 보관
+        hit(&mut world, shooter, enemy, 10);
+        assert_eq!(levels(&mut world), [2, 3]);
+        assert_eq!(world.get::<Xp>(shooter).unwrap().current, 5);
+    }
+
+    #[test]
+    fn dead_units_bank_xp_but_do_not_level_and_friendly_damage_gives_none() {
+        let mut world = world();
+        let shooter = grower(&mut world, 0, 3);
+        let ally = grower(&mut world, 0, 0);
+        let enemy = grower(&mut world, 1, 0);
+
+        hit(&mut world, shooter, ally, 6);
+        assert_eq!(world.get::<Xp>(shooter).unwrap().current, 0);
+        world.resource_mut::<Events<DamageApplied>>().clear();
+
+        world.get_mut::<Stats>(shooter).unwrap().hp = 0;
+        hit(&mut world, shooter, enemy, 6);
+        assert!(levels(&mut world).is_empty());
+        assert_eq!(world.get::<Level>(shooter).unwrap().0, 1);
+        assert_eq!(world.get::<Xp>(shooter).unwrap().current, 6);
+    }
+}
@@ -0,0 +1,31 @@
+// src/gameplay/units/systems/roster.rs
+use bevy::prelude::*;
+
+use crate::gameplay::units::{
+    components::{TeamId, Unit},
+    resources::{PlayerTeamId, SquadRoster},
+};
+
+/// 분대 명단 동기화
+/// - 새로 생긴 플레이어 팀 유닛은 끝에 추가(같은 프레임이면 엔티티 순)
+/// - Unit 마커가 빠진(사망/despawn) 유닛은 제거
+pub fn sync_squad_roster(
+    mut roster: ResMut<SquadRoster>,
+    player: Res<PlayerTeamId>,
+    q_added: Query<(Entity, &TeamId), Added<Unit>>,
+    mut removed: RemovedComponents<Unit>,
+) {
+    let gone: Vec<Entity> = removed.read().collect();
+    if !gone.is_empty() {
+        roster.units.retain(|e| !gone.contains(e));
+    }
+
+    let mut added: Vec<Entity> = q_added
+        .iter()
+        .filter(|(e, team)| team.0 == player.0 && !roster.units.contains(e))
+        .map(|(e, _)| e)
+        .collect();
+    if added.is_empty() { return; }
+    added.sort();
+    roster.units.extend(added);
+}
@@ -1,22 +1,50 @@
 // src/gameplay/units/systems/spawn_from_assets.rs
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 use crate::gameplay::tiles::events::GridPlace;
 use crate::gameplay::units::assets::{PendingUnitLoads};
+use crate::gameplay::units::events::UnitSpecInvalid;
 use crate::gameplay::units::spec::UnitSpec;
+use crate::gameplay::units::validate::validate;
 
-/// 로드 완료된 프리셋을 변환→스폰하고 타일에 올리기
+/// 로드 완료된 프리셋을 검사→변환→스폰하고 타일에 올리기
+/// - 값 검사에 걸린 스펙은 UnitSpecInvalid만 내고 스폰하지 않음
 pub fn process_loaded_units(
     mut commands: Commands,
+    asset_server: Res<AssetServer>,
     assets: Res<Assets<UnitSpec>>,
     mut pending: ResMut<PendingUnitLoads>,
     mut ev_place: EventWriter<GridPlace>,
+    mut ev_invalid: EventWriter<UnitSpecInvalid>,
 ) {
+    // 이번 호출에서 검사한 스펙 (같은 스펙 여러 번 예약 시 1회만 검사/보고)
+    let mut checked: HashMap<AssetId<UnitSpec>, bool> = HashMap::new();
     let mut remaining = Vec::with_capacity(pending.0.len());
     for (handle, pos) in pending.0.drain(..) {
         if let Some(spec) = assets.get(&handle) {
+            let valid = *checked.entry(handle.id()).or_insert_with(|| {
+                let errors = validate(spec);
+                if errors.is_empty() { return true; }
+                let path = handle.path().map_or_else(|| format!("{:?}", handle.id()), |p| p.to_string());
+                for e in &errors {
+                    warn!("unit spec '{path}': {e}");
+                }
+                ev_invalid.write(UnitSpecInvalid { path, errors });
+                false
+            });
+            if !valid { continue; }
+
             let bundle = spec.to_bundle();
-            let entity = commands.spawn(bundle).id();
+            let mut ec = commands.spawn(bundle);
+            if let Some(magazine) = spec.magazine() {
+                ec.insert(magazine);
+            }
+            let entity = ec.id();
             ev_place.write(GridPlace { entity, at: pos }); // ← 필드명이 pos 라면 이렇게
+        } else if asset_server.load_state(&handle).is_failed() {
+            // 파싱/상속 에러 — 사유는 에셋 서버가 이미 로그로 남김
+            warn!("unit spec {:?} failed to load, spawn at {:?} skipped", handle.path(), pos);
         } else {
             remaining.push((handle, pos)); // 아직 로드 안됨 → 다음 프레임 재시도
         }
@@ -0,0 +1,30 @@
+// src/gameplay/units/systems/terrain_costs.rs
+use bevy::prelude::*;
+
+use crate::gameplay::units::terrain_costs::{
+    TerrainCostPolicy, TerrainCostTable, TerrainCostTableHandle, TERRAIN_COSTS_PATH,
+};
+
+/// 지형 비용 테이블 로드 요청
+pub fn load_terrain_cost_table(
+    asset_server: Res<AssetServer>,
+    mut handle: ResMut<TerrainCostTableHandle>,
+) {
+    handle.0 = asset_server.load(TERRAIN_COSTS_PATH);
+}
+
+/// 테이블 로드/핫리로드 시 정책 리소스 갱신
+pub fn apply_terrain_cost_table(
+    mut ev: EventReader<AssetEvent<TerrainCostTable>>,
+    handle: Res<TerrainCostTableHandle>,
+    tables: Res<Assets<TerrainCostTable>>,
+    mut policy: ResMut<TerrainCostPolicy>,
+) {
+    for e in ev.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = e else { continue; };
+        if *id != handle.0.id() { continue; }
+        if let Some(table) = tables.get(*id) {
+            *policy = TerrainCostPolicy::from(table);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// src/gameplay/units/systems/xp_rules.rs
+use bevy::prelude::*;
+
+use crate::gameplay::units::{
+    resources::XpConfig,
+    xp_rules::{XpRules, XpRulesHandle, XP_RULES_PATH},
+};
+
+/// 경험치 규칙 로드 요청
+pub fn load_xp_rules(asset_server: Res<AssetServer>, mut handle: ResMut<XpRulesHandle>) {
+    handle.0 = asset_server.load(XP_RULES_PATH);
+}
+
+/// 규칙 로드/핫리로드 시 XpConfig 갱신
+pub fn apply_xp_rules(
+    mut ev: EventReader<AssetEvent<XpRules>>,
+    handle: Res<XpRulesHandle>,
+    rules: Res<Assets<XpRules>>,
+    mut cfg: ResMut<XpConfig>,
+) {
+    for e in ev.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = e else { continue; };
+        if *id != handle.0.id() { continue; }
+        if let Some(rules) = rules.get(*id) {
+            *cfg = XpConfig::from(rules);
+        }
+    }
+}
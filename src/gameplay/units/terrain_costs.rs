@@ -0,0 +1,100 @@
+// src/gameplay/units/terrain_costs.rs
+use std::collections::HashMap;
+
+use bevy::{asset::Asset, prelude::*, reflect::TypePath};
+use serde::Deserialize;
+
+use crate::gameplay::tiles::{components::TerrainKind, pathfinding::TerrainCosts};
+use super::{components::MovementClass, spec::MovementClassSpec};
+
+/// 기본 테이블 경로 (assets/ 기준)
+pub const TERRAIN_COSTS_PATH: &str = "rules/movement.costs.ron";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum TerrainKindSpec { Ground, Forest, Water, Wall, Road }
+
+impl From<TerrainKindSpec> for TerrainKind {
+    fn from(s: TerrainKindSpec) -> Self {
+        match s {
+            TerrainKindSpec::Ground => TerrainKind::Ground,
+            TerrainKindSpec::Forest => TerrainKind::Forest,
+            TerrainKindSpec::Water => TerrainKind::Water,
+            TerrainKindSpec::Wall => TerrainKind::Wall,
+            TerrainKindSpec::Road => TerrainKind::Road,
+        }
+    }
+}
+
+/// (MovementClass, TerrainKind) → 진입 비용 테이블 (RON 에셋)
+/// - 테이블에 없는 지형은 통과 불가
+/// - 테이블에 없는 이동 계열은 보병 기본 규칙(TerrainCosts::default)
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct TerrainCostTable {
+    pub classes: HashMap<MovementClassSpec, HashMap<TerrainKindSpec, u32>>,
+}
+
+/// 런타임 정책: 로드된 테이블을 이동 계열별 TerrainCosts로 펼친 것
+/// (로드 전에는 모든 계열이 보병 기본 규칙)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TerrainCostPolicy {
+    classes: HashMap<MovementClass, TerrainCosts>,
+}
+
+impl TerrainCostPolicy {
+    /// 이동 계열의 지형 비용
+    #[inline]
+    pub fn costs(&self, class: MovementClass) -> TerrainCosts {
+        self.classes.get(&class).copied().unwrap_or_default()
+    }
+}
+
+impl From<&TerrainCostTable> for TerrainCostPolicy {
+    fn from(table: &TerrainCostTable) -> Self {
+        let classes = table
+            .classes
+            .iter()
+            .map(|(&class, row)| {
+                let mut costs = TerrainCosts([None; 5]);
+                for (&kind, &cost) in row {
+                    costs.set(kind.into(), Some(cost));
+                }
+                (class.into(), costs)
+            })
+            .collect();
+        Self { classes }
+    }
+}
+
+/// 로드 중인 테이블 핸들
+#[derive(Resource, Debug, Default)]
+pub struct TerrainCostTableHandle(pub Handle<TerrainCostTable>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(src: &str) -> TerrainCostPolicy {
+        TerrainCostPolicy::from(&ron::from_str::<TerrainCostTable>(src).unwrap())
+    }
+
+    #[test]
+    fn shipped_table_gives_each_class_its_costs() {
+        let policy = policy(include_str!("../../../assets/rules/movement.costs.ron"));
+        let infantry = policy.costs(MovementClass::Infantry);
+        assert_eq!(infantry.get(TerrainKind::Water), None);
+        assert_eq!(infantry.get(TerrainKind::Forest), Some(1));
+        assert_eq!(policy.costs(MovementClass::Flyer).get(TerrainKind::Water), Some(1));
+        assert_eq!(policy.costs(MovementClass::Swimmer).get(TerrainKind::Ground), Some(2));
+        assert_eq!(policy.costs(MovementClass::Heavy).get(TerrainKind::Forest), Some(2));
+    }
+
+    #[test]
+    fn unlisted_terrain_is_impassable_and_unlisted_class_uses_default() {
+        let policy = policy("(classes: { Flyer: { Ground: 1 } })");
+        let flyer = policy.costs(MovementClass::Flyer);
+        assert_eq!(flyer.get(TerrainKind::Ground), Some(1));
+        assert_eq!(flyer.get(TerrainKind::Road), None);
+        assert_eq!(policy.costs(MovementClass::Heavy), TerrainCosts::default());
+        assert_eq!(TerrainCostPolicy::default().costs(MovementClass::Flyer), TerrainCosts::default());
+    }
+}
@@ -0,0 +1,171 @@
+// src/gameplay/units/validate.rs
+//! 로드된 UnitSpec 값 검사 (파싱/상속은 loader, 여기서는 값의 범위/모순만)
+//! 스폰 직전에 호출 — 하나라도 걸리면 그 스펙은 스폰하지 않는다.
+use std::fmt;
+
+use super::spec::{PropagationSpec, UnitSpec};
+
+/// 잘못된 값 1개: RON 기준 필드 경로 + 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecError {
+    /// 예: "attack.crit_chance", "growth[2].xp"
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// 검사 결과 모음 (필드 순서대로)
+#[derive(Default)]
+struct Errors(Vec<SpecError>);
+
+impl Errors {
+    fn check(&mut self, ok: bool, field: impl Into<String>, message: impl FnOnce() -> String) {
+        if !ok {
+            self.0.push(SpecError { field: field.into(), message: message() });
+        }
+    }
+}
+
+/// 스펙 전체 검사 — 비어 있으면 통과
+pub fn validate(spec: &UnitSpec) -> Vec<SpecError> {
+    let mut e = Errors::default();
+
+    e.check(!spec.name.trim().is_empty(), "name", || "must not be empty".into());
+    e.check(spec.max_hp > 0, "max_hp", || format!("must be > 0 (got {})", spec.max_hp));
+    e.check(spec.max_shield >= 0, "max_shield", || format!("must be >= 0 (got {})", spec.max_shield));
+
+    let a = &spec.attack;
+    e.check(a.damage >= 0, "attack.damage", || format!("must be >= 0 (got {})", a.damage));
+    e.check(a.range > 0, "attack.range", || "must be > 0".into());
+    e.check((0.0..=1.0).contains(&a.crit_chance), "attack.crit_chance", || {
+        format!("must be within 0.0..=1.0 (got {})", a.crit_chance)
+    });
+    e.check(a.crit_mult.is_finite() && a.crit_mult >= 1.0, "attack.crit_mult", || {
+        format!("must be >= 1.0 (got {})", a.crit_mult)
+    });
+    e.check(a.ap_cost <= spec.actions_per_turn, "attack.ap_cost", || {
+        format!("must be <= actions_per_turn ({} > {})", a.ap_cost, spec.actions_per_turn)
+    });
+
+    match spec.propagation {
+        PropagationSpec::None => {}
+        PropagationSpec::Pierce { times } => {
+            e.check(times > 0, "propagation.Pierce.times", || "must be > 0".into());
+        }
+        PropagationSpec::Ricochet { times, branches } => {
+            e.check(times > 0, "propagation.Ricochet.times", || "must be > 0".into());
+            e.check(branches > 0, "propagation.Ricochet.branches", || "must be > 0".into());
+        }
+        PropagationSpec::Chain { jumps, radius, .. } => {
+            e.check(jumps > 0, "propagation.Chain.jumps", || "must be > 0".into());
+            e.check(radius > 0, "propagation.Chain.radius", || "must be > 0".into());
+        }
+    }
+
+    if let Some(x) = spec.on_hit.explode {
+        e.check(x.radius > 0, "on_hit.explode.radius", || "must be > 0".into());
+        e.check(x.bonus_damage >= 0, "on_hit.explode.bonus_damage", || {
+            format!("must be >= 0 (got {})", x.bonus_damage)
+        });
+    }
+    if let Some(k) = spec.on_hit.knockback {
+        e.check(k.distance > 0, "on_hit.knockback.distance", || "must be > 0".into());
+        e.check(k.impact_damage >= 0, "on_hit.knockback.impact_damage", || {
+            format!("must be >= 0 (got {})", k.impact_damage)
+        });
+    }
+    if let Some(g) = spec.on_hit.gravity {
+        e.check(g.range > 0, "on_hit.gravity.range", || "must be > 0".into());
+        e.check(g.strength > 0, "on_hit.gravity.strength", || "must be > 0".into());
+    }
+
+    let im = &spec.imbue;
+    e.check(im.cooldown <= im.cooldown_max, "imbue.cooldown", || {
+        format!("must be <= imbue.cooldown_max ({} > {})", im.cooldown, im.cooldown_max)
+    });
+
+    if let Some(m) = spec.magazine {
+        e.check(m.capacity > 0, "magazine.capacity", || "must be > 0".into());
+    }
+
+    for (i, step) in spec.growth.iter().enumerate() {
+        e.check(step.xp > 0, format!("growth[{i}].xp"), || "must be > 0".into());
+        e.check(step.max_hp >= 0, format!("growth[{i}].max_hp"), || format!("must be >= 0 (got {})", step.max_hp));
+        e.check(step.damage >= 0, format!("growth[{i}].damage"), || format!("must be >= 0 (got {})", step.damage));
+    }
+
+    e.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::units::spec::{
+        ActionTraitsSpec, AimDirsSpec, AttackSpec, ElementalImbueSpec, FiringModsSpec, MovementClassSpec,
+        OnHitEffectsSpec,
+    };
+
+    fn valid() -> UnitSpec {
+        UnitSpec {
+            name: "Test".into(),
+            team: 0,
+            max_hp: 10,
+            max_shield: 0,
+            move_per_turn: 4,
+            actions_per_turn: 1,
+            movement_class: MovementClassSpec::Infantry,
+            attack: AttackSpec { damage: 3, range: 3, crit_chance: 0.05, crit_mult: 1.5, dirs: AimDirsSpec::Four, ap_cost: 1 },
+            traits_: ActionTraitsSpec { can_move_after_attack: false, auto_reload: false, can_phase: false },
+            firing: FiringModsSpec { burst: 0, multishot_rays: 0, fan_rays: 0, lobbed: false },
+            propagation: PropagationSpec::None,
+            on_hit: OnHitEffectsSpec { explode: None, knockback: None, gravity: None },
+            imbue: ElementalImbueSpec { element: None, cooldown_max: 0, cooldown: 0 },
+            magazine: None,
+            perk_slots: 0,
+            growth: Vec::new(),
+        }
+    }
+
+    fn fields(spec: &UnitSpec) -> Vec<String> {
+        validate(spec).into_iter().map(|e| e.field).collect()
+    }
+
+    #[test]
+    fn valid_spec_passes() {
+        assert!(validate(&valid()).is_empty());
+    }
+
+    #[test]
+    fn crit_chance_out_of_range() {
+        let mut spec = valid();
+        spec.attack.crit_chance = 5.0;
+        assert_eq!(fields(&spec), ["attack.crit_chance"]);
+    }
+
+    #[test]
+    fn negative_max_hp() {
+        let mut spec = valid();
+        spec.max_hp = -3;
+        assert_eq!(fields(&spec), ["max_hp"]);
+    }
+
+    #[test]
+    fn cooldown_above_max() {
+        let mut spec = valid();
+        spec.imbue.cooldown_max = 1;
+        spec.imbue.cooldown = 3;
+        assert_eq!(fields(&spec), ["imbue.cooldown"]);
+    }
+
+    #[test]
+    fn ricochet_without_branches() {
+        let mut spec = valid();
+        spec.propagation = PropagationSpec::Ricochet { times: 1, branches: 0 };
+        assert_eq!(fields(&spec), ["propagation.Ricochet.branches"]);
+    }
+}
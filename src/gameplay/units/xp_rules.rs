@@ -0,0 +1,37 @@
+// src/gameplay/units/xp_rules.rs
+use bevy::{asset::Asset, prelude::*, reflect::TypePath};
+use serde::Deserialize;
+
+use super::resources::XpConfig;
+
+/// 기본 경험치 규칙 경로 (assets/ 기준)
+pub const XP_RULES_PATH: &str = "rules/progression.xp.ron";
+
+/// 경험치 획득 규칙 (RON 에셋)
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct XpRules {
+    pub per_damage: u32,
+    pub per_kill: u32,
+}
+
+impl From<&XpRules> for XpConfig {
+    fn from(rules: &XpRules) -> Self {
+        Self { per_damage: rules.per_damage, per_kill: rules.per_kill }
+    }
+}
+
+/// 로드 중인 규칙 핸들
+#[derive(Resource, Debug, Default)]
+pub struct XpRulesHandle(pub Handle<XpRules>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_rules_parse_into_config() {
+        let rules: XpRules = ron::from_str(include_str!("../../../assets/rules/progression.xp.ron")).unwrap();
+        let cfg = XpConfig::from(&rules);
+        assert_eq!((cfg.per_damage, cfg.per_kill), (1, 5));
+    }
+}
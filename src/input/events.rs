@@ -4,6 +4,10 @@ use bevy::prelude::*;
 #[derive(Event, Debug, Clone, Copy)]
 pub struct KeyJustPressed(pub KeyCode);
 
+/// Shift+Tab — 역방향 순환 (이때는 KeyJustPressed(Tab)을 보내지 않음)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CycleBackPressed;
+
 /// 커서 이동/클릭 이벤트는 그대로 유지
 #[derive(Event, Debug, Clone, Copy)]
 pub struct CursorMovedWorld { pub world: Vec2, pub screen: Vec2 }
@@ -6,5 +6,5 @@ pub mod systems;
 
 // 바깥에서 쓸 때 편리하게 re-export
 pub use plugin::InputPlugin;
-pub use events::{CursorMovedWorld, CursorClickedWorld, KeyJustPressed};
+pub use events::{CursorMovedWorld, CursorClickedWorld, CycleBackPressed, KeyJustPressed};
 pub use resources::CursorWorldPos;
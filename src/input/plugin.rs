@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use crate::app::state::AppState;
 use super::{
-  events::{CursorMovedWorld, CursorClickedWorld, KeyJustPressed},
+  events::{CursorMovedWorld, CursorClickedWorld, CycleBackPressed, KeyJustPressed},
   resources::CursorWorldPos,
   systems::{update_cursor_world_pos, emit_clicks_world, emit_key_just_pressed},
 };
@@ -13,6 +13,7 @@ impl Plugin for InputPlugin {
       .add_event::<CursorMovedWorld>()
       .add_event::<CursorClickedWorld>()
       .add_event::<KeyJustPressed>()
+      .add_event::<CycleBackPressed>()
       .add_systems(PreUpdate, update_cursor_world_pos.run_if(in_state(AppState::Battle)))
       .add_systems(Update, (emit_clicks_world, emit_key_just_pressed).run_if(in_state(AppState::Battle)));
   }
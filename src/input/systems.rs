@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use super::{
     resources::CursorWorldPos,
-    events::{CursorMovedWorld, CursorClickedWorld, KeyJustPressed},
+    events::{CursorMovedWorld, CursorClickedWorld, CycleBackPressed, KeyJustPressed},
 };
 
 // 네가 만든 메인 카메라 마커를 불러온다.
@@ -64,19 +64,26 @@ pub fn emit_clicks_world(
     }
 }
 
+/// 감시 중인 키의 just_pressed → KeyJustPressed (Shift+Tab만 CycleBackPressed로 따로)
 pub fn emit_key_just_pressed(
     keys: Res<ButtonInput<KeyCode>>,
     mut ev_key: EventWriter<KeyJustPressed>,
+    mut ev_cycle_back: EventWriter<CycleBackPressed>,
 ) {
     // 전역 키맵: 여기선 몇 개만 예시. 필요 키를 추가/삭제하세요.
     const WATCH_KEYS: &[KeyCode] = &[
         KeyCode::Digit1, KeyCode::Digit2, KeyCode::Escape,
         KeyCode::Space,  KeyCode::KeyM,   KeyCode::KeyA,
+        KeyCode::KeyR,   KeyCode::Tab,
     ];
 
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
     for &k in WATCH_KEYS {
-        if keys.just_pressed(k) {
-            ev_key.send(KeyJustPressed(k));
+        if !keys.just_pressed(k) { continue; }
+        if k == KeyCode::Tab && shift {
+            ev_cycle_back.write(CycleBackPressed);
+        } else {
+            ev_key.write(KeyJustPressed(k));
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// 시너지 알림 목록의 루트 (화면 좌하단 고정)
+#[derive(Component, Debug)]
+pub struct SynergyFeedRoot;
+
+/// 알림 1줄 — 수명이 다하면 제거
+#[derive(Component, Debug)]
+pub struct SynergyToast {
+    pub timer: Timer,
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::app::state::AppState;
+use crate::view::ui::resources::{UiColors, UiLayout};
+use super::systems::{expire_synergy_toasts, push_synergy_toasts, spawn_synergy_feed_once};
+
+/// 속성 시너지 HUD: combat의 SynergyTriggered를 구독해 좌하단에 알림 표시
+pub struct ElementalSynergyPlugin;
+
+impl Plugin for ElementalSynergyPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UiColors>()
+            .init_resource::<UiLayout>()
+            .add_systems(OnEnter(AppState::Battle), spawn_synergy_feed_once)
+            .add_systems(
+                Update,
+                (push_synergy_toasts, expire_synergy_toasts)
+                    .run_if(in_state(AppState::Battle)),
+            );
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+use crate::gameplay::combat::SynergyTriggered;
+use crate::gameplay::units::components::Element;
+
+use super::components::*;
+use crate::view::ui::resources::{UiColors, UiLayout};
+
+/// 알림 1줄 표시 시간(초)
+const TOAST_SECS: f32 = 2.5;
+/// 동시에 보이는 최대 줄 수 (넘치면 오래된 것부터 제거)
+const MAX_TOASTS: usize = 5;
+
+/// 알림 목록 루트를 한 번만 생성
+pub fn spawn_synergy_feed_once(
+    mut commands: Commands,
+    layout: Res<UiLayout>,
+    q_existing: Query<Entity, With<SynergyFeedRoot>>,
+) {
+    if !q_existing.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            left: Val::Px(12.0),
+            row_gap: Val::Px(layout.panel_gap * 0.5),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexStart,
+            ..default()
+        },
+        SynergyFeedRoot,
+    ));
+}
+
+/// SynergyTriggered → 알림 1줄 추가 ("Melt  Fire + Ice")
+pub fn push_synergy_toasts(
+    mut commands: Commands,
+    mut ev_synergy: EventReader<SynergyTriggered>,
+    colors: Res<UiColors>,
+    layout: Res<UiLayout>,
+    q_root: Query<Entity, With<SynergyFeedRoot>>,
+    q_toasts: Query<Entity, With<SynergyToast>>,
+) {
+    let Ok(root) = q_root.single() else {
+        ev_synergy.clear();
+        return;
+    };
+
+    let mut count = q_toasts.iter().count();
+    let mut stale = q_toasts.iter();
+    for e in ev_synergy.read() {
+        if count >= MAX_TOASTS {
+            if let Some(old) = stale.next() {
+                commands.entity(old).despawn();
+            }
+        } else {
+            count += 1;
+        }
+
+        let mut line = format!("{}  {} + {}", e.name, element_label(e.existing), element_label(e.incoming));
+        if !e.spread.is_empty() {
+            line.push_str(&format!("  (spread x{})", e.spread.len()));
+        }
+        commands.entity(root).with_children(|p| {
+            p.spawn((
+                Text::new(line),
+                TextFont { font_size: layout.font_size_medium, ..default() },
+                TextColor(colors.text),
+                SynergyToast { timer: Timer::from_seconds(TOAST_SECS, TimerMode::Once) },
+            ));
+        });
+    }
+}
+
+/// 수명이 다한 알림 제거 (마지막 1/3 구간은 서서히 흐려짐)
+pub fn expire_synergy_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_toasts: Query<(Entity, &mut SynergyToast, &mut TextColor)>,
+) {
+    for (entity, mut toast, mut color) in &mut q_toasts {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = (toast.timer.fraction_remaining() * 3.0).min(1.0);
+        color.0.set_alpha(alpha);
+    }
+}
+
+fn element_label(el: Element) -> &'static str {
+    match el {
+        Element::Fire => "Fire",
+        Element::Ice => "Ice",
+        Element::Electric => "Electric",
+        Element::Poison => "Poison",
+    }
+}
@@ -1,4 +1,6 @@
 pub mod selection_panel;
+pub mod elemental_synergy;
+pub mod squad_roster;
 pub mod plugin;
 pub mod resources;
 
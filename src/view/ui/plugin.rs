@@ -1,12 +1,17 @@
 // view/ui/plugin.rs
 use bevy::prelude::*;
 use super::selection_panel::plugin::SelectionPanelPlugin;
+use super::elemental_synergy::plugin::ElementalSynergyPlugin;
+use super::squad_roster::plugin::SquadRosterPlugin;
 
-/// 인게임 UI(선택 패널 등)를 묶는 상위 플러그인
+/// 인게임 UI(선택 패널, 시너지 알림, 분대 명단 등)를 묶는 상위 플러그인
 pub struct UiViewPlugin;
 
 impl Plugin for UiViewPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(SelectionPanelPlugin);
+        app
+            .add_plugins(SelectionPanelPlugin)
+            .add_plugins(ElementalSynergyPlugin)
+            .add_plugins(SquadRosterPlugin);
     }
 }
@@ -50,6 +50,12 @@ pub struct UiLayout {
     pub font_size_small: f32,
     /// (선택) UI용 Z 레이어가 필요하면 보관
     pub z_ui_base: f32,
+    /// 분대 명단 줄에 표시하는 최대 유닛 수 (넘치는 유닛은 Tab 순환으로만 선택)
+    pub squad_strip_max: usize,
+    /// 분대 명단 초상 한 변(px)
+    pub roster_portrait_px: f32,
+    /// 분대 명단 HP 막대 높이(px)
+    pub roster_hp_bar_px: f32,
 }
 
 impl Default for UiLayout {
@@ -63,6 +69,9 @@ impl Default for UiLayout {
             font_size_medium: 16.0,
             font_size_small: 13.0,
             z_ui_base: layer::UI, // 프로젝트의 z_index가 있으면 사용, 아니면 0.0 등으로 바꿔도 됨
+            squad_strip_max: 6,
+            roster_portrait_px: 40.0,
+            roster_hp_bar_px: 5.0,
         }
     }
 }
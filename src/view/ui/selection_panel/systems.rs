@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use crate::{
     gameplay::{
         interaction::resources::SelectionCtx,
-        units::components::{Unit, TeamId, Stats, MoveBudget, ActionBudget, Attack},
+        units::components::{Unit, TeamId, Stats, MoveBudget, ActionBudget, Attack, Magazine, Level, Xp},
         units::resources::{TeamColors, Teams},
     },
 };
@@ -76,6 +76,8 @@ pub fn update_selection_panel_ui(
         Option<&MoveBudget>,
         Option<&ActionBudget>,
         Option<&Attack>,
+        Option<&Magazine>,
+        Option<(&Level, &Xp)>,
     ), With<Unit>>,
 ) {
     // SelectionCtx 또는 폰트/테마가 바뀌지 않았다면 일찍 종료해도 됨 (선택)
@@ -99,6 +101,8 @@ pub fn update_selection_panel_ui(
         move_opt,
         act_opt,
         attack_opt,
+        magazine_opt,
+        level_opt,
     )) = q_unit.get(selected) else {
         *vis = Visibility::Hidden;
         return;
@@ -120,6 +124,9 @@ pub fn update_selection_panel_ui(
     if let Ok(mut t) = q_name.single_mut() {
         let name_str = name_opt.map(|n| n.as_str()).unwrap_or("Unknown Unit");
         t.0 = format!("{name}  (Team {team})", name = name_str, team = team_id);
+        if let Some((level, xp)) = level_opt {
+            t.0.push_str(&format!("  Lv {}  XP {}", level.0, xp.current));
+        }
         // 패널 보더 색도 팀 색으로 살짝 강조
         border.0 = team_col;
     }
@@ -137,10 +144,13 @@ pub fn update_selection_panel_ui(
         if let Ok(mut t) = q_shield.single_mut() { t.0.clear(); }
     }
 
-    // Move/AP
+    // Move/AP (+ 탄창)
     if let (Some(mv), Some(ap)) = (move_opt, act_opt) {
         if let Ok(mut t) = q_move_ap.single_mut() {
             t.0 = format!("Move: {}/{}   AP: {}/{}", mv.current, mv.per_turn, ap.current, ap.per_turn);
+            if let Some(mag) = magazine_opt {
+                t.0.push_str(&format!("   Ammo: {}/{}", mag.current, mag.capacity));
+            }
         }
     } else if let Ok(mut t) = q_move_ap.single_mut() {
         t.0.clear();
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+/// 분대 명단 줄의 루트 (화면 하단 중앙 고정)
+#[derive(Component, Debug)]
+pub struct SquadStripRoot;
+
+/// 유닛 1칸 (초상 + HP) — 명단이 바뀌면 다시 만듦
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RosterSlot {
+    pub unit: Entity,
+}
+
+/// HP 막대 채움 (width %)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RosterHpFill {
+    pub unit: Entity,
+}
+
+/// HP 숫자 ("7/10")
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RosterHpText {
+    pub unit: Entity,
+}
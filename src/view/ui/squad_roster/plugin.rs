@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::app::state::AppState;
+use crate::view::ui::resources::{UiColors, UiLayout};
+use super::systems::{rebuild_squad_strip, spawn_squad_strip_once, update_squad_strip};
+
+/// 분대 명단 UI: SquadRoster 순서대로 초상/HP 표시, 선택·행동 가능 여부 강조
+pub struct SquadRosterPlugin;
+
+impl Plugin for SquadRosterPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UiColors>()
+            .init_resource::<UiLayout>()
+            .add_systems(OnEnter(AppState::Battle), spawn_squad_strip_once)
+            .add_systems(
+                Update,
+                (rebuild_squad_strip, update_squad_strip)
+                    .chain()
+                    .run_if(in_state(AppState::Battle)),
+            );
+    }
+}
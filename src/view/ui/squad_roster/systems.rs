@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use crate::gameplay::interaction::resources::SelectionCtx;
+use crate::gameplay::units::{
+    components::{ActionBudget, MoveBudget, Stats, TeamId, Unit},
+    resources::{SquadRoster, TeamColors, Teams},
+};
+
+use super::components::*;
+use crate::view::ui::resources::{UiColors, UiLayout};
+
+/// 명단 줄 루트를 한 번만 생성
+pub fn spawn_squad_strip_once(
+    mut commands: Commands,
+    layout: Res<UiLayout>,
+    q_existing: Query<Entity, With<SquadStripRoot>>,
+) {
+    if !q_existing.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            column_gap: Val::Px(layout.panel_gap),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        SquadStripRoot,
+    ));
+}
+
+/// SquadRoster가 바뀌면 칸을 다시 만듦 (초상 = 팀 색 + 이름 첫 글자)
+pub fn rebuild_squad_strip(
+    mut commands: Commands,
+    roster: Res<SquadRoster>,
+    layout: Res<UiLayout>,
+    teams: Res<Teams>,
+    team_colors: Res<TeamColors>,
+    q_root: Query<(Entity, Ref<SquadStripRoot>)>,
+    q_unit: Query<(Option<&Name>, &TeamId)>,
+) {
+    let Ok((root, marker)) = q_root.single() else { return; };
+    // 루트가 막 생긴 프레임에도 한 번 채움
+    if !(roster.is_changed() || marker.is_added()) {
+        return;
+    }
+
+    commands.entity(root).despawn_related::<Children>();
+    for &unit in roster.units.iter().take(layout.squad_strip_max) {
+        let Ok((name, team)) = q_unit.get(unit) else { continue; };
+        let color = if *team == teams.enemy { team_colors.enemy } else { team_colors.ally };
+        let initial: String = name.and_then(|n| n.as_str().chars().next()).unwrap_or('?').to_uppercase().collect();
+
+        commands.entity(root).with_children(|p| {
+            p.spawn((
+                Node {
+                    padding: UiRect::all(Val::Px(4.0)),
+                    row_gap: Val::Px(3.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                BorderColor(Color::NONE),
+                RosterSlot { unit },
+            ))
+            .with_children(|slot| {
+                // 초상
+                slot.spawn((
+                    Node {
+                        width: Val::Px(layout.roster_portrait_px),
+                        height: Val::Px(layout.roster_portrait_px),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                ))
+                .with_children(|portrait| {
+                    portrait.spawn((
+                        Text::new(initial),
+                        TextFont { font_size: layout.font_size_large, ..default() },
+                        TextColor(Color::BLACK),
+                    ));
+                });
+                // HP 막대
+                slot.spawn((
+                    Node {
+                        width: Val::Px(layout.roster_portrait_px),
+                        height: Val::Px(layout.roster_hp_bar_px),
+                        ..default()
+                    },
+                    BackgroundColor(Color::BLACK.with_alpha(0.6)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node { width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() },
+                        BackgroundColor(Color::NONE),
+                        RosterHpFill { unit },
+                    ));
+                });
+                // HP 숫자
+                slot.spawn((
+                    Text::new(""),
+                    TextFont { font_size: layout.font_size_small, ..default() },
+                    RosterHpText { unit },
+                ));
+            });
+        });
+    }
+}
+
+/// 매 프레임: HP 막대/숫자, 선택 테두리, 행동 가능 여부(소진 시 흐리게)
+pub fn update_squad_strip(
+    sel: Res<SelectionCtx>,
+    colors: Res<UiColors>,
+    q_unit: Query<(&Stats, &MoveBudget, &ActionBudget), With<Unit>>,
+    mut q_slots: Query<(&RosterSlot, &mut BorderColor, &mut BackgroundColor), Without<RosterHpFill>>,
+    mut q_fills: Query<(&RosterHpFill, &mut Node, &mut BackgroundColor), Without<RosterSlot>>,
+    mut q_texts: Query<(&RosterHpText, &mut Text, &mut TextColor)>,
+) {
+    for (slot, mut border, mut bg) in &mut q_slots {
+        let ready = q_unit.get(slot.unit).is_ok_and(|(_, mv, ap)| mv.current > 0 || ap.current > 0);
+        let selected = sel.selected_unit == Some(slot.unit);
+        border.0 = if selected { colors.text } else { colors.panel_border };
+        bg.0 = if ready { colors.panel_bg } else { colors.panel_bg.with_alpha(0.4) };
+    }
+
+    for (fill, mut node, mut bg) in &mut q_fills {
+        let Ok((stats, ..)) = q_unit.get(fill.unit) else { continue; };
+        let ratio = if stats.max_hp > 0 { stats.hp.max(0) as f32 / stats.max_hp as f32 } else { 0.0 };
+        node.width = Val::Percent(ratio * 100.0);
+        bg.set_if_neq(BackgroundColor(colors.hp));
+    }
+
+    for (hp, mut text, mut color) in &mut q_texts {
+        let Ok((stats, mv, ap)) = q_unit.get(hp.unit) else { continue; };
+        text.0 = format!("{}/{}", stats.hp, stats.max_hp);
+        color.0 = if mv.current > 0 || ap.current > 0 { colors.text } else { colors.text_dim };
+    }
+}
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
 
 use crate::gameplay::units::resources::{Teams, TeamColors};
+use crate::gameplay::units::UnitLifecycleSet;
 use super::{
     resources::UnitViewConfig,
-    systems::{spawn_unit_sprites_for_new_units, sync_unit_sprites},
+    systems::{spawn_unit_sprites_for_new_units, sync_unit_sprites, despawn_sprites_of_dead_units},
 };
 
 pub struct UnitViewPlugin;
@@ -21,6 +22,10 @@ impl Plugin for UnitViewPlugin {
             (
                 spawn_unit_sprites_for_new_units,
                 sync_unit_sprites,
+                // 같은 프레임에 유닛 본체가 despawn되기 전에 자식 스프라이트/링크를 먼저 정리
+                despawn_sprites_of_dead_units
+                    .after(UnitLifecycleSet::Detect)
+                    .before(UnitLifecycleSet::Cleanup),
             ),
         );
     }
@@ -7,6 +7,7 @@ use crate::gameplay::{
     },
     units::{
         components::{Unit, TeamId},
+        events::UnitDied,
         resources::{Teams, TeamColors},
     },
 };
@@ -121,3 +122,16 @@ pub fn sync_unit_sprites(
         }
     }
 }
+
+/// 사망한 유닛의 자식 스프라이트 제거 (시체로 남는 경우에도 링크는 끊음)
+pub fn despawn_sprites_of_dead_units(
+    mut commands: Commands,
+    mut ev_died: EventReader<UnitDied>,
+    q_links: Query<&UnitSpriteLink>,
+) {
+    for e in ev_died.read() {
+        let Ok(link) = q_links.get(e.entity) else { continue; };
+        commands.entity(link.0).despawn();
+        commands.entity(e.entity).remove::<UnitSpriteLink>();
+    }
+}